@@ -1,6 +1,6 @@
 """Filesystem tools for local project access in chat sessions."""
 
-from test_ai.tools.safety import PathValidator, SecurityError
+from test_ai.tools.safety import PathValidator, PolicyViolation, SecurityError
 from test_ai.tools.models import (
     FileContent,
     DirectoryListing,
@@ -15,6 +15,7 @@ from test_ai.tools.proposals import ProposalManager
 __all__ = [
     "PathValidator",
     "SecurityError",
+    "PolicyViolation",
     "FileContent",
     "DirectoryListing",
     "SearchResult",
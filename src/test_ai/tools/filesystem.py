@@ -6,20 +6,57 @@ All operations use PathValidator for security.
 from __future__ import annotations
 
 import fnmatch
+import hashlib
+import heapq
+import itertools
 import os
 import re
+import shutil
+import stat
+import threading
+import time
+from collections.abc import Callable, Iterator
+from concurrent.futures import ThreadPoolExecutor
+from datetime import datetime, timedelta, timezone
 from pathlib import Path
 from typing import TYPE_CHECKING
 
+from test_ai.tools.fuzzy import fuzzy_match
+from test_ai.tools.ignore import IgnoreMatcher
+from test_ai.tools.language import detect_language as _detect_language
 from test_ai.tools.models import (
     DirectoryListing,
+    DirectorySize,
+    DiskSpace,
+    DuplicateGroup,
+    DuplicateReport,
     FileContent,
+    FileOperation,
     FileEntry,
+    FuzzyFindResult,
+    FuzzyMatch,
+    LanguageInfo,
+    PathKind,
+    PathStatus,
+    PolicyCheck,
+    ProjectInfo,
     ProjectStructure,
+    RecentFile,
+    RecentFiles,
     SearchMatch,
     SearchResult,
+    TokenEstimate,
+    TokenReport,
+)
+from test_ai.tools.project import detect_project as _detect_project
+from test_ai.tools.safety import PathValidator, PolicyViolation, SecurityError
+from test_ai.tools.tokens import (
+    BINARY_SNIFF_BYTES,
+    chars_per_token,
+    count_chars,
+    estimate_method,
+    tokens_for_chars,
 )
-from test_ai.tools.safety import PathValidator, SecurityError
 
 if TYPE_CHECKING:
     pass
@@ -29,6 +66,29 @@ if TYPE_CHECKING:
 DEFAULT_MAX_RESULTS = 100
 DEFAULT_MAX_TREE_DEPTH = 4
 
+# Default look-back window for recent_files
+DEFAULT_RECENT_WINDOW = timedelta(days=1)
+
+# Seconds before the fuzzy_find file index is rebuilt
+DEFAULT_FILE_INDEX_TTL = 30.0
+
+# Mount table consulted for filesystem types (Linux only)
+PROC_MOUNTS = Path("/proc/mounts")
+
+# Bytes hashed per file when pre-filtering duplicate candidates
+PARTIAL_HASH_BYTES = 4096
+HASH_CHUNK_BYTES = 1024 * 1024
+
+# Upper bound on file content read by one search_code call
+DEFAULT_MAX_SCAN_BYTES = 256 * 1024 * 1024
+
+# search_code reads files on a thread pool; reads dominate, so threads help
+SEARCH_WORKERS = 8
+SEARCH_BATCH_SIZE = SEARCH_WORKERS * 4
+
+# Leading content inspected for shebangs and language signatures
+LANGUAGE_SAMPLE_CHARS = 1024
+
 
 class FilesystemTools:
     """Filesystem operations for local project access.
@@ -50,12 +110,15 @@ class FilesystemTools:
         self.validator = validator
         self.max_results = max_results
         self.project_root = validator.get_project_root()
+        self._file_index: list[str] | None = None
+        self._file_index_built_at = 0.0
 
     def read_file(
         self,
         path: str,
         start_line: int | None = None,
         end_line: int | None = None,
+        include_language: bool = False,
     ) -> FileContent:
         """Read a file's content with optional line range.
 
@@ -63,6 +126,7 @@ class FilesystemTools:
             path: Path to file (relative or absolute).
             start_line: Start line (1-indexed, inclusive). None for start.
             end_line: End line (1-indexed, inclusive). None for end.
+            include_language: Whether to detect the file's language.
 
         Returns:
             FileContent with file content and metadata.
@@ -97,19 +161,202 @@ class FilesystemTools:
         for i, line in enumerate(lines, start=line_offset + 1):
             numbered_lines.append(f"{i:>6}\t{line.rstrip()}")
 
+        language = None
+        if include_language:
+            language, _ = _detect_language(resolved, content[:LANGUAGE_SAMPLE_CHARS])
+
         return FileContent(
             path=rel_path,
             content="\n".join(numbered_lines),
             line_count=total_lines,
             size_bytes=resolved.stat().st_size,
             truncated=truncated,
+            language=language,
+        )
+
+    def detect_language(self, path: str, sample: str | None = None) -> LanguageInfo:
+        """Detect a file's language for syntax highlighting.
+
+        Args:
+            path: Path to file (relative or absolute). The file need not
+                exist when a sample is given.
+            sample: Leading file content. None reads it from the file.
+
+        Returns:
+            LanguageInfo; unknown files are reported as plaintext.
+
+        Raises:
+            SecurityError: If path fails validation.
+        """
+        resolved = self.validator.validate_path(path)
+
+        if sample is None and resolved.is_file():
+            try:
+                with resolved.open("rb") as f:
+                    head = f.read(LANGUAGE_SAMPLE_CHARS)
+            except OSError:
+                head = b""
+            sample = head.decode("utf-8", errors="replace")
+
+        language, confidence = _detect_language(resolved, sample)
+        return LanguageInfo(path=path, language=language, confidence=confidence)
+
+    def path_kind(self, path: str) -> PathStatus:
+        """Report what kind of entry exists at a path.
+
+        Symlinks are not followed, so a dangling link is reported as an
+        existing symlink rather than as missing.
+
+        Args:
+            path: Path to inspect (relative or absolute).
+
+        Returns:
+            PathStatus describing the entry.
+
+        Raises:
+            PolicyViolation: If the project policy forbids reading the path.
+            SecurityError: If path fails validation.
+        """
+        target = Path(os.path.normpath(self.project_root / path))
+
+        # Validate the containing directory so the entry itself is not resolved
+        rel_path = None
+        if target == self.project_root:
+            candidate = self.validator.validate_path(target)
+        else:
+            try:
+                parent = self.validator.validate_path(target.parent)
+            except PolicyViolation as e:
+                raise PolicyViolation(path, e.operation, e.rule) from None
+            candidate = parent / target.name
+            try:
+                rel_path = str(candidate.relative_to(self.project_root))
+            except ValueError:
+                pass  # Under an additional allowed path
+            if rel_path and self.validator.is_excluded(rel_path):
+                raise SecurityError(f"Path matches excluded pattern: {path}")
+
+        try:
+            mode: int | None = candidate.lstat().st_mode
+        except OSError:
+            mode = None
+
+        is_dir = mode is not None and stat.S_ISDIR(mode)
+        self.validator.enforce_policy(path, rel_path, FileOperation.READ, is_dir)
+
+        if mode is None:
+            return PathStatus(path=path, exists=False, kind=PathKind.NONE)
+
+        if stat.S_ISLNK(mode):
+            return PathStatus(
+                path=path,
+                exists=True,
+                kind=PathKind.SYMLINK,
+                is_broken_symlink=not candidate.exists(),
+            )
+        if stat.S_ISDIR(mode):
+            kind = PathKind.DIR
+        elif stat.S_ISREG(mode):
+            kind = PathKind.FILE
+        else:
+            kind = PathKind.OTHER
+
+        return PathStatus(path=path, exists=True, kind=kind)
+
+    def disk_space(self, path: str = ".") -> DiskSpace:
+        """Report free and total space on the volume containing a path.
+
+        A path that does not exist yet (e.g. a planned output directory)
+        resolves to its nearest existing ancestor's volume.
+
+        Args:
+            path: Path to check (relative or absolute).
+
+        Returns:
+            DiskSpace for the containing volume.
+
+        Raises:
+            SecurityError: If path fails validation.
+        """
+        existing = self.validator.validate_path(path)
+        while not existing.exists():
+            existing = existing.parent
+
+        usage = shutil.disk_usage(existing)
+
+        mount_point = existing
+        while not os.path.ismount(mount_point) and mount_point != mount_point.parent:
+            mount_point = mount_point.parent
+
+        return DiskSpace(
+            path=path,
+            total_bytes=usage.total,
+            available_bytes=usage.free,
+            filesystem=self._filesystem_type(mount_point),
+            mount_point=str(mount_point),
         )
 
+    @staticmethod
+    def _filesystem_type(mount_point: Path) -> str | None:
+        """Look up a mount point's filesystem type in /proc/mounts, if present."""
+        try:
+            lines = PROC_MOUNTS.read_text().splitlines()
+        except OSError:
+            return None
+
+        fs_type = None
+        for line in lines:
+            fields = line.split()
+            # Later entries shadow earlier ones mounted at the same point
+            if len(fields) >= 3 and fields[1] == str(mount_point):
+                fs_type = fields[2]
+        return fs_type
+
+    def policy_check(
+        self,
+        path: str,
+        operation: FileOperation = FileOperation.READ,
+    ) -> PolicyCheck:
+        """Check whether an operation on a path would be permitted.
+
+        Covers the project bounds, exclude patterns, and .gorgonpolicy, so
+        callers can disable actions up front instead of waiting for a
+        SecurityError.
+
+        Args:
+            path: Path to check (relative or absolute).
+            operation: Operation to check.
+
+        Returns:
+            PolicyCheck with the reason and, for policy denials, the rule.
+        """
+        return self.validator.policy_check(path, operation)
+
+    def detect_project(self, path: str = ".") -> ProjectInfo:
+        """Detect the build ecosystems declared in a directory.
+
+        Mixed repositories report every manifest found. Manifests that fail
+        to parse are still listed, with the error recorded in warnings.
+
+        Args:
+            path: Directory to inspect (default: project root).
+
+        Returns:
+            ProjectInfo with one entry per detected manifest.
+
+        Raises:
+            SecurityError: If path fails validation.
+        """
+        resolved = self.validator.validate_directory(path)
+        rel_path = str(resolved.relative_to(self.project_root))
+        return _detect_project(resolved, rel_path)
+
     def list_files(
         self,
         path: str = ".",
         pattern: str | None = None,
         recursive: bool = False,
+        respect_ignores: bool = True,
     ) -> DirectoryListing:
         """List files in a directory.
 
@@ -117,6 +364,8 @@ class FilesystemTools:
             path: Directory path (relative or absolute).
             pattern: Optional glob pattern to filter files.
             recursive: Whether to list recursively.
+            respect_ignores: Whether to skip paths matched by .gitignore
+                             and .gorgonignore files.
 
         Returns:
             DirectoryListing with directory contents.
@@ -127,6 +376,7 @@ class FilesystemTools:
         resolved = self.validator.validate_directory(path)
         rel_path = str(resolved.relative_to(self.project_root))
 
+        ignores = self._ignore_matcher(respect_ignores)
         entries: list[FileEntry] = []
         total_files = 0
         total_dirs = 0
@@ -141,7 +391,7 @@ class FilesystemTools:
             # Skip excluded paths
             try:
                 item_rel = str(item.relative_to(self.project_root))
-                if self.validator.is_excluded(item_rel):
+                if self._is_hidden(item_rel, item.is_dir(), ignores):
                     continue
             except ValueError:
                 continue
@@ -185,8 +435,19 @@ class FilesystemTools:
         file_pattern: str | None = None,
         case_sensitive: bool = True,
         max_results: int | None = None,
+        respect_ignores: bool = True,
+        regex: bool = True,
+        filenames_only: bool = False,
+        include_globs: list[str] | None = None,
+        exclude_globs: list[str] | None = None,
+        max_scan_bytes: int = DEFAULT_MAX_SCAN_BYTES,
     ) -> SearchResult:
-        """Search for a pattern in files.
+        """Search for a pattern in files, or in file paths.
+
+        Files are read and matched on a thread pool, with results kept in
+        walk order. Binary files (a NUL byte near the start) and files over
+        the read size limit are skipped. Globs without a slash match file names;
+        globs with a slash match paths relative to the project root.
 
         Args:
             pattern: Regex pattern to search for.
@@ -194,6 +455,16 @@ class FilesystemTools:
             file_pattern: Optional glob pattern to filter files.
             case_sensitive: Whether search is case-sensitive.
             max_results: Maximum number of matches to return.
+            respect_ignores: Whether to skip paths matched by .gitignore
+                             and .gorgonignore files.
+            regex: Whether pattern is a regex; False searches for it literally.
+            filenames_only: Match relative file paths instead of contents.
+                            Matches have line_number 0 and the path as
+                            line_content.
+            include_globs: Only search files matching one of these globs.
+            exclude_globs: Skip files matching any of these globs.
+            max_scan_bytes: Stop once this many bytes of content have been
+                            read; the result is marked truncated.
 
         Returns:
             SearchResult with matches.
@@ -206,71 +477,76 @@ class FilesystemTools:
 
         flags = 0 if case_sensitive else re.IGNORECASE
         try:
-            regex = re.compile(pattern, flags)
+            compiled = re.compile(pattern if regex else re.escape(pattern), flags)
         except re.error as e:
             raise SecurityError(f"Invalid regex pattern: {e}")
 
         matches: list[SearchMatch] = []
         files_searched = 0
+        bytes_scanned = 0
         truncated = False
 
-        # Walk through files
-        for root, _, files in os.walk(resolved):
-            root_path = Path(root)
-
-            for filename in files:
-                file_path = root_path / filename
+        ignores = self._ignore_matcher(respect_ignores)
 
+        def candidates() -> Iterator[tuple[Path, str]]:
+            for file_path, rel_path in self._walk_files(resolved, ignores=ignores):
                 # Apply file pattern filter
-                if file_pattern and not fnmatch.fnmatch(filename, file_pattern):
+                if file_pattern and not fnmatch.fnmatch(file_path.name, file_pattern):
                     continue
-
-                # Check exclusions
-                try:
-                    rel_path = str(file_path.relative_to(self.project_root))
-                    if self.validator.is_excluded(rel_path):
-                        continue
-                except ValueError:
+                if include_globs and not _matches_any(rel_path, include_globs):
                     continue
-
-                # Check file is readable
-                try:
-                    self.validator.validate_file_for_read(file_path)
-                except SecurityError:
+                if exclude_globs and _matches_any(rel_path, exclude_globs):
                     continue
+                yield file_path, rel_path
 
+        if filenames_only:
+            for _, rel_path in candidates():
                 files_searched += 1
-
-                # Search file content
-                try:
-                    content = file_path.read_text(encoding="utf-8")
-                except (OSError, UnicodeDecodeError):
+                match = compiled.search(rel_path)
+                if match is None:
                     continue
-
-                for line_num, line in enumerate(content.splitlines(), start=1):
-                    for match in regex.finditer(line):
-                        if len(matches) >= max_res:
+                if len(matches) >= max_res:
+                    truncated = True
+                    break
+                matches.append(
+                    SearchMatch(
+                        path=rel_path,
+                        line_number=0,
+                        line_content=rel_path,
+                        match_start=match.start(),
+                        match_end=match.end(),
+                    )
+                )
+        else:
+            # Files are read and matched on worker threads a batch at a time;
+            # results are consumed in walk order so limits apply as if serial
+            pending = candidates()
+            with ThreadPoolExecutor(max_workers=SEARCH_WORKERS) as executor:
+                while not truncated:
+                    batch = list(itertools.islice(pending, SEARCH_BATCH_SIZE))
+                    if not batch:
+                        break
+                    scans = executor.map(
+                        lambda item: self._scan_file(*item, compiled, max_res), batch
+                    )
+                    for scan in scans:
+                        if scan is None:
+                            continue
+                        if bytes_scanned >= max_scan_bytes:
                             truncated = True
                             break
-
-                        matches.append(
-                            SearchMatch(
-                                path=rel_path,
-                                line_number=line_num,
-                                line_content=line.strip(),
-                                match_start=match.start(),
-                                match_end=match.end(),
-                            )
-                        )
-
-                    if truncated:
-                        break
-
-                if truncated:
-                    break
-
-            if truncated:
-                break
+                        size, file_matches = scan
+                        bytes_scanned += size
+                        if file_matches is None:
+                            continue
+
+                        files_searched += 1
+                        room = max_res - len(matches)
+                        if len(file_matches) > room:
+                            matches.extend(file_matches[:room])
+                            truncated = True
+                            break
+                        matches.extend(file_matches)
 
         return SearchResult(
             pattern=pattern,
@@ -280,18 +556,77 @@ class FilesystemTools:
             truncated=truncated,
         )
 
+    def _scan_file(
+        self,
+        file_path: Path,
+        rel_path: str,
+        compiled: re.Pattern[str],
+        max_matches: int,
+    ) -> tuple[int, list[SearchMatch] | None] | None:
+        """Read and search one file for search_code.
+
+        Returns:
+            None if the file was not read (too large or unreadable);
+            otherwise (bytes read, matches), with matches None for binary
+            or non-UTF-8 files. At most max_matches + 1 matches are kept
+            so the caller can tell the limit was exceeded.
+        """
+        # The walk already applied bounds, excludes, and policy
+        try:
+            if file_path.stat().st_size > self.validator.max_file_size:
+                return None
+            data = file_path.read_bytes()
+        except OSError:
+            return None
+
+        if b"\0" in data[:BINARY_SNIFF_BYTES]:
+            return len(data), None
+        try:
+            content = data.decode("utf-8")
+        except UnicodeDecodeError:
+            return len(data), None
+
+        matches: list[SearchMatch] = []
+        for line_num, line in enumerate(content.splitlines(), start=1):
+            # Offsets index into the stripped line; clamp matches that
+            # fall partly in leading or trailing whitespace
+            stripped = line.strip()
+            indent = len(line) - len(line.lstrip())
+            for match in compiled.finditer(line):
+                start, end = (
+                    min(max(offset - indent, 0), len(stripped))
+                    for offset in match.span()
+                )
+                matches.append(
+                    SearchMatch(
+                        path=rel_path,
+                        line_number=line_num,
+                        line_content=stripped,
+                        match_start=start,
+                        match_end=end,
+                    )
+                )
+                if len(matches) > max_matches:
+                    return len(data), matches
+
+        return len(data), matches
+
     def get_structure(
         self,
         max_depth: int = DEFAULT_MAX_TREE_DEPTH,
+        respect_ignores: bool = True,
     ) -> ProjectStructure:
         """Get an overview of the project structure.
 
         Args:
             max_depth: Maximum depth for tree representation.
+            respect_ignores: Whether to skip paths matched by .gitignore
+                             and .gorgonignore files.
 
         Returns:
             ProjectStructure with tree and file stats.
         """
+        ignores = self._ignore_matcher(respect_ignores)
         tree_lines: list[str] = []
         total_files = 0
         total_dirs = 0
@@ -315,7 +650,7 @@ class FilesystemTools:
             for entry in entries:
                 try:
                     rel_path = str(entry.relative_to(self.project_root))
-                    if not self.validator.is_excluded(rel_path):
+                    if not self._is_hidden(rel_path, entry.is_dir(), ignores):
                         visible_entries.append(entry)
                 except ValueError:
                     pass  # Graceful degradation: path not relative to root, skip entry
@@ -353,21 +688,25 @@ class FilesystemTools:
     def glob_files(
         self,
         pattern: str,
+        respect_ignores: bool = True,
     ) -> list[str]:
         """Find files matching a glob pattern.
 
         Args:
             pattern: Glob pattern (e.g., "**/*.py").
+            respect_ignores: Whether to skip paths matched by .gitignore
+                             and .gorgonignore files.
 
         Returns:
             List of relative paths matching the pattern.
         """
+        ignores = self._ignore_matcher(respect_ignores)
         results: list[str] = []
 
         for path in self.project_root.glob(pattern):
             try:
                 rel_path = str(path.relative_to(self.project_root))
-                if not self.validator.is_excluded(rel_path) and path.is_file():
+                if path.is_file() and not self._is_hidden(rel_path, False, ignores):
                     results.append(rel_path)
             except ValueError:
                 pass  # Graceful degradation: path not relative to root, skip
@@ -376,3 +715,503 @@ class FilesystemTools:
                 break
 
         return sorted(results)
+
+    def recent_files(
+        self,
+        path: str = ".",
+        since: datetime | None = None,
+        limit: int | None = None,
+        cancel_event: threading.Event | None = None,
+        respect_ignores: bool = True,
+    ) -> RecentFiles:
+        """List files modified since a cutoff, newest first.
+
+        Excluded directories are pruned before descending, so their contents
+        are never statted. Cheap enough to poll on a timer.
+
+        Args:
+            path: Directory to walk.
+            since: Modification time cutoff; naive values are taken as local
+                   time. Defaults to the last 24 hours.
+            limit: Maximum number of files to return.
+            cancel_event: Optional event; when set, the walk stops and the
+                          files found so far are returned.
+            respect_ignores: Whether to skip paths matched by .gitignore
+                             and .gorgonignore files.
+
+        Returns:
+            RecentFiles sorted by modification time, newest first.
+
+        Raises:
+            SecurityError: If path fails validation.
+        """
+        resolved = self.validator.validate_directory(path)
+        rel_path = str(resolved.relative_to(self.project_root))
+        max_res = limit or self.max_results
+
+        cutoff = since or datetime.now(timezone.utc) - DEFAULT_RECENT_WINDOW
+        # Naive datetimes are local time, as elsewhere in Python
+        cutoff = cutoff.astimezone(timezone.utc)
+        cutoff_ts = cutoff.timestamp()
+
+        found: list[tuple[float, str, int]] = []
+        ignores = self._ignore_matcher(respect_ignores)
+        for file_path, file_rel in self._walk_files(resolved, cancel_event, ignores):
+            try:
+                stat_result = file_path.stat()
+            except OSError:
+                continue  # Vanished or unreadable since listing, skip
+            if stat_result.st_mtime >= cutoff_ts:
+                found.append((stat_result.st_mtime, file_rel, stat_result.st_size))
+
+        found.sort(key=lambda item: item[0], reverse=True)
+
+        return RecentFiles(
+            path=rel_path,
+            since=cutoff,
+            files=[
+                RecentFile(
+                    path=file_rel,
+                    size_bytes=size,
+                    modified_at=datetime.fromtimestamp(mtime, tz=timezone.utc),
+                )
+                for mtime, file_rel, size in found[:max_res]
+            ],
+            truncated=len(found) > max_res,
+            cancelled=cancel_event is not None and cancel_event.is_set(),
+        )
+
+    def directory_sizes(
+        self,
+        path: str = ".",
+        depth: int = 1,
+        progress_callback: Callable[[DirectorySize], None] | None = None,
+        respect_ignores: bool = True,
+    ) -> DirectorySize:
+        """Break down a directory's disk usage by subdirectory.
+
+        Every node carries recursive totals computed in a single walk.
+        Symlinks are not followed and hard-linked files are counted once.
+        Unreadable entries are counted in the node's errors field rather
+        than failing the call.
+
+        Args:
+            path: Directory to measure.
+            depth: Number of subdirectory levels to include in the tree.
+            progress_callback: Called with each top-level child's totals as
+                               soon as that child has been measured.
+            respect_ignores: Whether to skip paths matched by .gitignore
+                             and .gorgonignore files.
+
+        Returns:
+            DirectorySize for the directory, with children up to depth.
+
+        Raises:
+            SecurityError: If path fails validation.
+        """
+        resolved = self.validator.validate_directory(path)
+        ignores = self._ignore_matcher(respect_ignores)
+        seen_inodes: set[tuple[int, int]] = set()
+
+        def measure(directory: Path, level: int) -> DirectorySize:
+            node = DirectorySize(path=str(directory.relative_to(self.project_root)))
+
+            try:
+                entries = list(os.scandir(directory))
+            except OSError:
+                node.errors += 1
+                return node
+
+            for entry in entries:
+                entry_path = Path(entry.path)
+                entry_rel = str(entry_path.relative_to(self.project_root))
+
+                try:
+                    if entry.is_symlink():
+                        continue
+                    if self._is_hidden(entry_rel, entry.is_dir(), ignores):
+                        continue
+
+                    if entry.is_dir(follow_symlinks=False):
+                        child = measure(entry_path, level + 1)
+                        node.size_bytes += child.size_bytes
+                        node.file_count += child.file_count
+                        node.errors += child.errors
+                        if level < depth:
+                            node.children.append(child)
+                        if level == 0 and progress_callback:
+                            progress_callback(child)
+                    elif entry.is_file(follow_symlinks=False):
+                        stat_result = entry.stat(follow_symlinks=False)
+                        if stat_result.st_nlink > 1 and stat_result.st_ino:
+                            inode = (stat_result.st_dev, stat_result.st_ino)
+                            if inode in seen_inodes:
+                                continue
+                            seen_inodes.add(inode)
+                        node.size_bytes += stat_result.st_size
+                        node.file_count += 1
+                except OSError:
+                    node.errors += 1
+
+            node.children.sort(key=lambda c: c.size_bytes, reverse=True)
+            return node
+
+        return measure(resolved, 0)
+
+    def fuzzy_find(
+        self,
+        query: str,
+        limit: int | None = None,
+        refresh: bool = False,
+    ) -> FuzzyFindResult:
+        """Find files whose paths fuzzily match a query, best first.
+
+        Paths are read from a cached index of non-ignored project files,
+        rebuilt when older than DEFAULT_FILE_INDEX_TTL seconds, on request,
+        or after invalidate_file_index().
+
+        Args:
+            query: Characters to match in order, e.g. "fsys" for filesystem.py.
+            limit: Maximum number of matches to return.
+            refresh: Rebuild the file index before matching.
+
+        Returns:
+            FuzzyFindResult with matches and highlight positions.
+        """
+        max_res = limit or self.max_results
+        index = self._get_file_index(refresh)
+
+        scored: list[tuple[int, int, str, list[int]]] = []
+        for rel_path in index:
+            result = fuzzy_match(query, rel_path)
+            if result is not None:
+                score, positions = result
+                scored.append((score, -len(rel_path), rel_path, positions))
+
+        best = heapq.nlargest(max_res, scored, key=lambda item: (item[0], item[1]))
+
+        return FuzzyFindResult(
+            query=query,
+            matches=[
+                FuzzyMatch(path=rel_path, score=score, positions=positions)
+                for score, _, rel_path, positions in best
+            ],
+            indexed_files=len(index),
+            truncated=len(scored) > max_res,
+        )
+
+    def find_duplicates(
+        self,
+        path: str = ".",
+        min_size: int = 1,
+        cancel_event: threading.Event | None = None,
+        progress_callback: Callable[[int, int], None] | None = None,
+        respect_ignores: bool = True,
+    ) -> DuplicateReport:
+        """Find groups of files with identical content.
+
+        Candidates are narrowed by size, then by a hash of their first few
+        kilobytes, and only then hashed in full. Hard links to the same
+        file are not reported as duplicates of each other. Every group has
+        a keeper, so removing all duplicates never loses content.
+
+        Args:
+            path: Directory to scan.
+            min_size: Skip files smaller than this many bytes.
+            cancel_event: Optional event; when set, the scan stops and the
+                          groups confirmed so far are returned.
+            progress_callback: Called with (bytes hashed, total candidate
+                               bytes) as full hashing progresses.
+            respect_ignores: Whether to skip paths matched by .gitignore
+                             and .gorgonignore files.
+
+        Returns:
+            DuplicateReport with groups sorted by reclaimable bytes.
+
+        Raises:
+            SecurityError: If path fails validation.
+        """
+        resolved = self.validator.validate_directory(path)
+        rel_path = str(resolved.relative_to(self.project_root))
+        ignores = self._ignore_matcher(respect_ignores)
+
+        def cancelled() -> bool:
+            return cancel_event is not None and cancel_event.is_set()
+
+        by_size: dict[int, list[tuple[str, os.stat_result]]] = {}
+        seen_inodes: set[tuple[int, int]] = set()
+        files_scanned = 0
+        for file_path, file_rel in self._walk_files(resolved, cancel_event, ignores):
+            try:
+                stat_result = file_path.stat(follow_symlinks=False)
+            except OSError:
+                continue
+            if not stat.S_ISREG(stat_result.st_mode):
+                continue
+            if stat_result.st_size < min_size:
+                continue
+            files_scanned += 1
+            if stat_result.st_nlink > 1 and stat_result.st_ino:
+                inode = (stat_result.st_dev, stat_result.st_ino)
+                if inode in seen_inodes:
+                    continue
+                seen_inodes.add(inode)
+            by_size.setdefault(stat_result.st_size, []).append((file_rel, stat_result))
+
+        by_partial: dict[tuple[int, str], list[tuple[str, os.stat_result]]] = {}
+        for size, files in by_size.items():
+            if len(files) < 2 or cancelled():
+                continue
+            for file_rel, stat_result in files:
+                digest = self._hash_file(file_rel, PARTIAL_HASH_BYTES)
+                if digest is not None:
+                    by_partial.setdefault((size, digest), []).append(
+                        (file_rel, stat_result)
+                    )
+
+        candidates = [files for files in by_partial.values() if len(files) > 1]
+        total_bytes = sum(files[0][1].st_size * len(files) for files in candidates)
+        hashed_bytes = 0
+
+        groups: list[DuplicateGroup] = []
+        for files in candidates:
+            by_hash: dict[str, list[tuple[str, os.stat_result]]] = {}
+            for file_rel, stat_result in files:
+                if cancelled():
+                    break
+                digest = self._hash_file(file_rel)
+                hashed_bytes += stat_result.st_size
+                if progress_callback:
+                    progress_callback(hashed_bytes, total_bytes)
+                if digest is not None:
+                    by_hash.setdefault(digest, []).append((file_rel, stat_result))
+
+            # Never report a group that was only partly hashed
+            if cancelled():
+                break
+
+            for digest, same in by_hash.items():
+                if len(same) < 2:
+                    continue
+                same.sort(key=lambda item: (item[1].st_mtime, len(item[0]), item[0]))
+                groups.append(
+                    DuplicateGroup(
+                        size_bytes=same[0][1].st_size,
+                        sha256=digest,
+                        keeper=same[0][0],
+                        duplicates=[file_rel for file_rel, _ in same[1:]],
+                    )
+                )
+
+        groups.sort(key=lambda g: g.size_bytes * len(g.duplicates), reverse=True)
+
+        return DuplicateReport(
+            path=rel_path,
+            groups=groups,
+            reclaimable_bytes=sum(g.size_bytes * len(g.duplicates) for g in groups),
+            files_scanned=files_scanned,
+            cancelled=cancelled(),
+        )
+
+    def estimate_tokens(
+        self,
+        paths: list[str],
+        model_hint: str | None = None,
+    ) -> TokenReport:
+        """Estimate how many context tokens each file would take.
+
+        Args:
+            paths: Files to estimate (relative or absolute).
+            model_hint: Model name used to pick a calibration, e.g.
+                        "claude-sonnet". None uses the default ratio.
+
+        Returns:
+            TokenReport with per-file estimates, the total, and the method.
+            Missing, binary, and unreadable files are listed as skipped.
+
+        Raises:
+            SecurityError: If any path fails validation.
+        """
+        resolved = [(self.validator.validate_path(p), p) for p in paths]
+        ratio = chars_per_token(model_hint)
+
+        files = []
+        for file_path, requested in resolved:
+            if not file_path.is_file():
+                files.append(TokenEstimate(path=requested, skipped="not a file"))
+                continue
+            files.append(
+                self._estimate_file(
+                    file_path, str(file_path.relative_to(self.project_root)), ratio
+                )
+            )
+
+        return TokenReport(
+            method=estimate_method(ratio),
+            files=files,
+            total_tokens=sum(f.tokens or 0 for f in files),
+        )
+
+    def estimate_tokens_tree(
+        self,
+        path: str = ".",
+        include_globs: list[str] | None = None,
+        exclude_globs: list[str] | None = None,
+        model_hint: str | None = None,
+        respect_ignores: bool = True,
+    ) -> TokenReport:
+        """Estimate the token size of every file under a directory.
+
+        Globs without a slash match file names; globs with a slash match
+        paths relative to the project root.
+
+        Args:
+            path: Directory to walk.
+            include_globs: Only count files matching one of these globs.
+            exclude_globs: Skip files matching any of these globs.
+            model_hint: Model name used to pick a calibration.
+            respect_ignores: Whether to skip paths matched by .gitignore
+                             and .gorgonignore files.
+
+        Returns:
+            TokenReport with per-file estimates, the total, and the method.
+
+        Raises:
+            SecurityError: If path fails validation.
+        """
+        resolved = self.validator.validate_directory(path)
+        ratio = chars_per_token(model_hint)
+        ignores = self._ignore_matcher(respect_ignores)
+
+        files = []
+        for file_path, rel_path in self._walk_files(resolved, ignores=ignores):
+            if include_globs and not _matches_any(rel_path, include_globs):
+                continue
+            if exclude_globs and _matches_any(rel_path, exclude_globs):
+                continue
+            files.append(self._estimate_file(file_path, rel_path, ratio))
+
+        return TokenReport(
+            method=estimate_method(ratio),
+            files=files,
+            total_tokens=sum(f.tokens or 0 for f in files),
+        )
+
+    def _estimate_file(
+        self, file_path: Path, rel_path: str, ratio: float
+    ) -> TokenEstimate:
+        """Estimate one file's tokens, recording why it was skipped if so."""
+        try:
+            chars = count_chars(file_path)
+        except OSError:
+            return TokenEstimate(path=rel_path, skipped="unreadable")
+        if chars is None:
+            return TokenEstimate(path=rel_path, skipped="binary")
+        return TokenEstimate(path=rel_path, tokens=tokens_for_chars(chars, ratio))
+
+    def _hash_file(self, rel_path: str, limit: int | None = None) -> str | None:
+        """SHA-256 of a file's content (or its first limit bytes), or None."""
+        digest = hashlib.sha256()
+        remaining = limit
+        try:
+            with open(self.project_root / rel_path, "rb") as f:
+                while remaining is None or remaining > 0:
+                    chunk_size = HASH_CHUNK_BYTES
+                    if remaining is not None:
+                        chunk_size = min(chunk_size, remaining)
+                        remaining -= chunk_size
+                    chunk = f.read(chunk_size)
+                    if not chunk:
+                        break
+                    digest.update(chunk)
+        except OSError:
+            return None
+        return digest.hexdigest()
+
+    def invalidate_file_index(self) -> None:
+        """Discard the cached fuzzy_find index so the next query rebuilds it."""
+        self._file_index = None
+
+    def _get_file_index(self, refresh: bool = False) -> list[str]:
+        """Return the cached list of project files, rebuilding when stale."""
+        age = time.monotonic() - self._file_index_built_at
+        if refresh or self._file_index is None or age > DEFAULT_FILE_INDEX_TTL:
+            ignores = self._ignore_matcher(True)
+            self._file_index = [
+                rel for _, rel in self._walk_files(self.project_root, ignores=ignores)
+            ]
+            self._file_index_built_at = time.monotonic()
+        return self._file_index
+
+    def _walk_files(
+        self,
+        directory: Path,
+        cancel_event: threading.Event | None = None,
+        ignores: IgnoreMatcher | None = None,
+    ) -> Iterator[tuple[Path, str]]:
+        """Yield (path, relative path) for visible files under a directory.
+
+        Excluded and ignored directories are pruned in place so they are
+        never descended. Symlinked files are only yielded when their target
+        is readable under the validator's rules, so callers never stat or
+        open files outside the project. Stops early when cancel_event is set.
+        """
+        for root, dirs, files in os.walk(directory):
+            if cancel_event is not None and cancel_event.is_set():
+                return
+
+            root_path = Path(root)
+            kept_dirs = []
+            for dirname in dirs:
+                try:
+                    dir_rel = str((root_path / dirname).relative_to(self.project_root))
+                except ValueError:
+                    continue
+                if not self._is_hidden(dir_rel, True, ignores):
+                    kept_dirs.append(dirname)
+            dirs[:] = kept_dirs
+
+            for filename in files:
+                file_path = root_path / filename
+                try:
+                    file_rel = str(file_path.relative_to(self.project_root))
+                except ValueError:
+                    continue
+                if self._is_hidden(file_rel, False, ignores):
+                    continue
+                if os.path.islink(file_path) and not (
+                    self.validator.is_readable_target(file_path.resolve())
+                ):
+                    continue
+                yield file_path, file_rel
+
+    def _ignore_matcher(self, respect_ignores: bool) -> IgnoreMatcher | None:
+        """Prepare path filtering for one recursive operation.
+
+        Reloads the access policy if it changed, so _is_hidden can check
+        every entry without re-reading it, and creates a fresh ignore
+        matcher if requested.
+        """
+        self.validator.refresh_policy()
+        return IgnoreMatcher(self.project_root) if respect_ignores else None
+
+    def _is_hidden(
+        self,
+        rel_path: str,
+        is_dir: bool,
+        ignores: IgnoreMatcher | None,
+    ) -> bool:
+        """Check a path against exclusions, policy, and optionally ignore files."""
+        if self.validator.is_excluded(rel_path):
+            return True
+        if self.validator.is_denied(rel_path, is_dir):
+            return True
+        return ignores is not None and ignores.is_ignored(rel_path, is_dir)
+
+
+def _matches_any(rel_path: str, globs: list[str]) -> bool:
+    """Match a relative path against name globs and path globs."""
+    name = rel_path.rsplit("/", 1)[-1]
+    return any(
+        fnmatch.fnmatch(rel_path if "/" in glob else name, glob) for glob in globs
+    )
@@ -0,0 +1,117 @@
+"""Fuzzy subsequence matching for quick-open style file search.
+
+Scoring follows the approach popularized by fzf: every query character
+must appear in order, matches at word boundaries and runs of consecutive
+characters score higher, and gaps between matched characters cost points.
+Matching is case-insensitive unless the query contains an uppercase letter.
+"""
+
+from __future__ import annotations
+
+SCORE_MATCH = 16
+SCORE_GAP_START = -3
+SCORE_GAP_EXTENSION = -1
+BONUS_PATH_SEPARATOR = 10
+BONUS_BOUNDARY = 8
+BONUS_CAMEL_CASE = 7
+BONUS_CONSECUTIVE = 5
+FIRST_CHAR_BONUS_MULTIPLIER = 2
+
+_WORD_SEPARATORS = "_-. "
+_PATH_SEPARATORS = "/\\"
+
+
+def fuzzy_match(query: str, candidate: str) -> tuple[int, list[int]] | None:
+    """Score a candidate against a query.
+
+    Args:
+        query: Characters to find, in order.
+        candidate: Text to search, typically a relative path.
+
+    Returns:
+        (score, matched character positions), or None if the candidate does
+        not contain the query as a subsequence.
+    """
+    if not query:
+        return 0, []
+
+    case_sensitive = any(c.isupper() for c in query)
+    needle = query if case_sensitive else query.lower()
+    haystack = candidate if case_sensitive else candidate.lower()
+
+    positions = _match_window(needle, haystack, 0)
+    if positions is None:
+        return None
+    score = _score(candidate, positions)
+
+    # Prefer a match entirely within the file name when it scores better
+    name_start = max(candidate.rfind(sep) for sep in _PATH_SEPARATORS) + 1
+    if name_start > 0:
+        in_name = _match_window(needle, haystack, name_start)
+        if in_name is not None:
+            in_name_score = _score(candidate, in_name)
+            if in_name_score > score:
+                score, positions = in_name_score, in_name
+
+    return score, positions
+
+
+def _match_window(needle: str, haystack: str, start: int) -> list[int] | None:
+    """Find the tightest in-order match starting the scan at start.
+
+    Scans forward for the first complete subsequence, then backward from
+    its end to shrink the window as far as possible.
+    """
+    pos = start
+    for ch in needle:
+        pos = haystack.find(ch, pos)
+        if pos == -1:
+            return None
+        pos += 1
+
+    end = pos - 1
+    positions = [end]
+    pos = end
+    for ch in reversed(needle[:-1]):
+        pos = haystack.rfind(ch, start, pos)
+        positions.append(pos)
+
+    positions.reverse()
+    return positions
+
+
+def _score(candidate: str, positions: list[int]) -> int:
+    """Score matched positions in a candidate."""
+    score = 0
+    prev_pos = -1
+
+    for i, pos in enumerate(positions):
+        bonus = _boundary_bonus(candidate, pos)
+        score += SCORE_MATCH
+
+        if i == 0:
+            score += bonus * FIRST_CHAR_BONUS_MULTIPLIER
+        elif pos == prev_pos + 1:
+            score += bonus + BONUS_CONSECUTIVE
+        else:
+            gap = pos - prev_pos - 1
+            score += bonus + SCORE_GAP_START + SCORE_GAP_EXTENSION * (gap - 1)
+
+        prev_pos = pos
+
+    return score
+
+
+def _boundary_bonus(candidate: str, pos: int) -> int:
+    """Bonus for a match that starts a path segment or word."""
+    if pos == 0:
+        return BONUS_PATH_SEPARATOR
+
+    prev = candidate[pos - 1]
+    if prev in _PATH_SEPARATORS:
+        return BONUS_PATH_SEPARATOR
+    if prev in _WORD_SEPARATORS:
+        return BONUS_BOUNDARY
+    if prev.islower() and candidate[pos].isupper():
+        return BONUS_CAMEL_CASE
+    return 0
@@ -0,0 +1,204 @@
+"""Ignore-file matching shared by recursive filesystem tools.
+
+Follows gitignore semantics:
+- .gitignore files are honored at every directory level, deeper files
+  taking precedence over shallower ones
+- Negated patterns (!pattern) re-include earlier matches
+- A path inside an ignored directory cannot be re-included
+- An optional .gorgonignore at the project root is applied last
+"""
+
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass
+from pathlib import Path
+
+GITIGNORE_FILENAME = ".gitignore"
+GORGONIGNORE_FILENAME = ".gorgonignore"
+
+
+@dataclass(frozen=True)
+class IgnoreRule:
+    """A single parsed ignore pattern."""
+
+    pattern: str
+    regex: re.Pattern[str]
+    negated: bool
+    dir_only: bool
+    anchored: bool
+
+    def matches(self, rel_path: str, is_dir: bool) -> bool:
+        """Check a path relative to the rule's base directory."""
+        if self.dir_only and not is_dir:
+            return False
+        target = rel_path if self.anchored else rel_path.rsplit("/", 1)[-1]
+        return self.regex.fullmatch(target) is not None
+
+
+def parse_ignore_lines(lines: list[str]) -> list[IgnoreRule]:
+    """Parse gitignore-style lines into rules, skipping blanks and comments."""
+    rules: list[IgnoreRule] = []
+
+    for raw in lines:
+        line = raw.rstrip("\r\n")
+        if not line.endswith("\\ "):
+            line = line.rstrip(" ")
+        if not line or line.startswith("#"):
+            continue
+
+        negated = line.startswith("!")
+        if negated:
+            line = line[1:]
+        elif line.startswith(("\\!", "\\#")):
+            line = line[1:]
+
+        dir_only = line.endswith("/")
+        line = line.rstrip("/")
+        if not line:
+            continue
+
+        anchored = "/" in line
+        line = line.lstrip("/")
+
+        rules.append(
+            IgnoreRule(
+                pattern=raw.strip(),
+                regex=re.compile(_glob_to_regex(line)),
+                negated=negated,
+                dir_only=dir_only,
+                anchored=anchored,
+            )
+        )
+
+    return rules
+
+
+def _glob_to_regex(pattern: str) -> str:
+    """Translate a gitignore glob into a regular expression."""
+    out: list[str] = []
+    i = 0
+    n = len(pattern)
+
+    while i < n:
+        if i == 0 and pattern.startswith("**/"):
+            out.append("(?:.*/)?")
+            i += 3
+        elif pattern.startswith("/**/", i):
+            out.append("/(?:.*/)?")
+            i += 4
+        elif pattern[i:] == "/**":
+            out.append("/.*")
+            i += 3
+        elif pattern == "**":
+            out.append(".*")
+            i += 2
+        elif pattern[i] == "*":
+            while i < n and pattern[i] == "*":
+                i += 1
+            out.append("[^/]*")
+        elif pattern[i] == "?":
+            out.append("[^/]")
+            i += 1
+        elif pattern[i] == "[":
+            end = pattern.find("]", i + 2)
+            if end == -1:
+                out.append(re.escape("["))
+                i += 1
+                continue
+            body = pattern[i + 1 : end]
+            if body[0] in "!^":
+                body = "^" + body[1:]
+            out.append("[" + body.replace("\\", "\\\\") + "]")
+            i = end + 1
+        elif pattern[i] == "\\" and i + 1 < n:
+            out.append(re.escape(pattern[i + 1]))
+            i += 2
+        else:
+            out.append(re.escape(pattern[i]))
+            i += 1
+
+    return "".join(out)
+
+
+class IgnoreMatcher:
+    """Decides whether project paths are ignored by .gitignore/.gorgonignore.
+
+    Ignore files are read lazily as directories are queried, so a fresh
+    matcher per operation always reflects the files on disk.
+    """
+
+    def __init__(self, project_root: str | Path):
+        """Initialize the matcher.
+
+        Args:
+            project_root: Root directory that relative paths are based on.
+        """
+        self.project_root = Path(project_root)
+        self._rules_by_dir: dict[str, list[IgnoreRule]] = {}
+        self._dir_results: dict[str, bool] = {}
+        self._project_rules = self._load(self.project_root / GORGONIGNORE_FILENAME)
+
+    def is_ignored(self, rel_path: str | Path, is_dir: bool = False) -> bool:
+        """Check whether a path relative to the project root is ignored.
+
+        Args:
+            rel_path: Path relative to the project root.
+            is_dir: Whether the path is a directory.
+
+        Returns:
+            True if the path or any of its parent directories is ignored.
+        """
+        normalized = str(rel_path).replace("\\", "/").strip("/")
+        if not normalized or normalized == ".":
+            return False
+
+        parts = normalized.split("/")
+        for depth in range(1, len(parts)):
+            if self._is_dir_ignored("/".join(parts[:depth])):
+                return True
+
+        return self._evaluate(parts, is_dir)
+
+    def _is_dir_ignored(self, rel_dir: str) -> bool:
+        """Evaluate a directory on its own, caching the result."""
+        cached = self._dir_results.get(rel_dir)
+        if cached is None:
+            cached = self._evaluate(rel_dir.split("/"), is_dir=True)
+            self._dir_results[rel_dir] = cached
+        return cached
+
+    def _evaluate(self, parts: list[str], is_dir: bool) -> bool:
+        """Apply every applicable rule in precedence order; last match wins."""
+        ignored = False
+
+        for depth in range(len(parts)):
+            base = "/".join(parts[:depth])
+            rel_to_base = "/".join(parts[depth:])
+            for rule in self._rules_for(base):
+                if rule.matches(rel_to_base, is_dir):
+                    ignored = not rule.negated
+
+        full = "/".join(parts)
+        for rule in self._project_rules:
+            if rule.matches(full, is_dir):
+                ignored = not rule.negated
+
+        return ignored
+
+    def _rules_for(self, rel_dir: str) -> list[IgnoreRule]:
+        """Return the .gitignore rules declared in a directory."""
+        rules = self._rules_by_dir.get(rel_dir)
+        if rules is None:
+            rules = self._load(self.project_root / rel_dir / GITIGNORE_FILENAME)
+            self._rules_by_dir[rel_dir] = rules
+        return rules
+
+    @staticmethod
+    def _load(path: Path) -> list[IgnoreRule]:
+        """Parse an ignore file, treating a missing or unreadable file as empty."""
+        try:
+            text = path.read_text(encoding="utf-8", errors="replace")
+        except OSError:
+            return []
+        return parse_ignore_lines(text.splitlines())
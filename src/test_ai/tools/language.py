@@ -0,0 +1,268 @@
+"""Language detection for syntax highlighting and file metadata.
+
+Detection combines, in order of precedence:
+1. Well-known file names (Makefile, Dockerfile, CMakeLists.txt, ...)
+2. File extensions, with content checks for ambiguous ones like .h
+3. The interpreter named on a shebang line
+4. A few content signatures (<?php, <?xml, <!DOCTYPE html)
+
+Every result is one of LANGUAGE_IDS. Unknown files are "plaintext" with
+zero confidence; detection never raises.
+"""
+
+from __future__ import annotations
+
+import re
+from pathlib import PurePath
+
+PLAINTEXT = "plaintext"
+
+# Documented set of language ids; mapping values must come from here.
+LANGUAGE_IDS = frozenset(
+    {
+        "c",
+        "cmake",
+        "cpp",
+        "csharp",
+        "css",
+        "dart",
+        "diff",
+        "dockerfile",
+        "go",
+        "graphql",
+        "html",
+        "ini",
+        "java",
+        "javascript",
+        "javascriptreact",
+        "json",
+        "kotlin",
+        "lua",
+        "makefile",
+        "markdown",
+        "objective-c",
+        "perl",
+        "php",
+        "plaintext",
+        "powershell",
+        "proto",
+        "python",
+        "r",
+        "ruby",
+        "rust",
+        "scss",
+        "shell",
+        "sql",
+        "svelte",
+        "swift",
+        "toml",
+        "typescript",
+        "typescriptreact",
+        "vue",
+        "xml",
+        "yaml",
+    }
+)
+
+CONFIDENCE_FILENAME = 0.95
+CONFIDENCE_EXTENSION = 0.9
+CONFIDENCE_SHEBANG = 0.85
+CONFIDENCE_CONTENT = 0.6
+CONFIDENCE_AMBIGUOUS = 0.5
+
+FILENAME_LANGUAGES: dict[str, str] = {
+    "makefile": "makefile",
+    "gnumakefile": "makefile",
+    "dockerfile": "dockerfile",
+    "containerfile": "dockerfile",
+    "cmakelists.txt": "cmake",
+    "gemfile": "ruby",
+    "rakefile": "ruby",
+    "vagrantfile": "ruby",
+    "cargo.lock": "toml",
+    "pipfile": "toml",
+    ".bashrc": "shell",
+    ".bash_profile": "shell",
+    ".zshrc": "shell",
+    ".profile": "shell",
+    ".gitconfig": "ini",
+    ".editorconfig": "ini",
+}
+
+EXTENSION_LANGUAGES: dict[str, str] = {
+    ".c": "c",
+    ".cc": "cpp",
+    ".cpp": "cpp",
+    ".cxx": "cpp",
+    ".hpp": "cpp",
+    ".hh": "cpp",
+    ".hxx": "cpp",
+    ".m": "objective-c",
+    ".mm": "objective-c",
+    ".cs": "csharp",
+    ".css": "css",
+    ".scss": "scss",
+    ".dart": "dart",
+    ".diff": "diff",
+    ".patch": "diff",
+    ".go": "go",
+    ".graphql": "graphql",
+    ".gql": "graphql",
+    ".html": "html",
+    ".htm": "html",
+    ".ini": "ini",
+    ".cfg": "ini",
+    ".java": "java",
+    ".js": "javascript",
+    ".mjs": "javascript",
+    ".cjs": "javascript",
+    ".jsx": "javascriptreact",
+    ".ts": "typescript",
+    ".mts": "typescript",
+    ".cts": "typescript",
+    ".tsx": "typescriptreact",
+    ".json": "json",
+    ".kt": "kotlin",
+    ".kts": "kotlin",
+    ".lua": "lua",
+    ".mk": "makefile",
+    ".md": "markdown",
+    ".markdown": "markdown",
+    ".pl": "perl",
+    ".pm": "perl",
+    ".php": "php",
+    ".ps1": "powershell",
+    ".psm1": "powershell",
+    ".proto": "proto",
+    ".py": "python",
+    ".pyi": "python",
+    ".r": "r",
+    ".rb": "ruby",
+    ".rs": "rust",
+    ".sh": "shell",
+    ".bash": "shell",
+    ".zsh": "shell",
+    ".sql": "sql",
+    ".svelte": "svelte",
+    ".swift": "swift",
+    ".toml": "toml",
+    ".vue": "vue",
+    ".xml": "xml",
+    ".svg": "xml",
+    ".yaml": "yaml",
+    ".yml": "yaml",
+    ".dockerfile": "dockerfile",
+    ".cmake": "cmake",
+}
+
+INTERPRETER_LANGUAGES: dict[str, str] = {
+    "python": "python",
+    "node": "javascript",
+    "deno": "typescript",
+    "bun": "javascript",
+    "ts-node": "typescript",
+    "sh": "shell",
+    "bash": "shell",
+    "zsh": "shell",
+    "dash": "shell",
+    "ksh": "shell",
+    "ruby": "ruby",
+    "perl": "perl",
+    "php": "php",
+    "lua": "lua",
+    "pwsh": "powershell",
+    "rscript": "r",
+    "make": "makefile",
+}
+
+# Header files are shared between C, C++, and Objective-C
+_CPP_MARKERS = re.compile(
+    r"^\s*(?:class\s+\w+|namespace\s+\w+|template\s*<|public:|private:)|std::",
+    re.MULTILINE,
+)
+_OBJC_MARKERS = re.compile(r"^\s*@(?:interface|protocol|end)\b", re.MULTILINE)
+_INTERPRETER_VERSION = re.compile(r"[\d.]+$")
+
+
+def detect_language(
+    path: str | PurePath, sample: str | None = None
+) -> tuple[str, float]:
+    """Detect a file's language from its name and optional leading content.
+
+    Args:
+        path: File path; only the name is used.
+        sample: Leading text of the file, used for shebangs and content
+            checks. None skips content-based detection.
+
+    Returns:
+        (language id from LANGUAGE_IDS, confidence between 0 and 1).
+    """
+    name = PurePath(path).name.lower()
+
+    language = FILENAME_LANGUAGES.get(name)
+    if language is not None:
+        return language, CONFIDENCE_FILENAME
+    if name.startswith(("dockerfile.", "containerfile.")):
+        return "dockerfile", CONFIDENCE_FILENAME
+
+    suffix = PurePath(name).suffix
+    if suffix == ".h":
+        return _detect_header(sample)
+
+    language = EXTENSION_LANGUAGES.get(suffix)
+    if language is not None:
+        return language, CONFIDENCE_EXTENSION
+
+    if sample:
+        language = _detect_shebang(sample)
+        if language is not None:
+            return language, CONFIDENCE_SHEBANG
+        language = _detect_content(sample)
+        if language is not None:
+            return language, CONFIDENCE_CONTENT
+
+    return PLAINTEXT, 0.0
+
+
+def _detect_header(sample: str | None) -> tuple[str, float]:
+    """Tell C, C++, and Objective-C headers apart by their content."""
+    if sample:
+        if _OBJC_MARKERS.search(sample):
+            return "objective-c", CONFIDENCE_CONTENT
+        if _CPP_MARKERS.search(sample):
+            return "cpp", CONFIDENCE_CONTENT
+    return "c", CONFIDENCE_AMBIGUOUS
+
+
+def _detect_shebang(sample: str) -> str | None:
+    """Map the interpreter on a #! line to a language."""
+    first_line = sample.split("\n", 1)[0].strip()
+    if not first_line.startswith("#!"):
+        return None
+
+    words = first_line[2:].split()
+    if not words:
+        return None
+
+    # "#!/usr/bin/env -S python3 -u" names the interpreter after env's flags
+    interpreter = PurePath(words[0]).name
+    if interpreter == "env":
+        args = [w for w in words[1:] if not w.startswith("-") and "=" not in w]
+        if not args:
+            return None
+        interpreter = PurePath(args[0]).name
+
+    interpreter = _INTERPRETER_VERSION.sub("", interpreter.lower())
+    return INTERPRETER_LANGUAGES.get(interpreter)
+
+
+def _detect_content(sample: str) -> str | None:
+    """Recognize a few unambiguous leading signatures."""
+    head = sample.lstrip("\ufeff \t\r\n")[:256].lower()
+    if head.startswith("<?php"):
+        return "php"
+    if head.startswith("<?xml"):
+        return "xml"
+    if head.startswith(("<!doctype html", "<html")):
+        return "html"
+    return None
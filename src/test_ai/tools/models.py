@@ -22,6 +22,36 @@ class ProposalStatus(str, Enum):
     FAILED = "failed"
 
 
+class PathKind(str, Enum):
+    """Kind of filesystem entry found at a path."""
+
+    FILE = "file"
+    DIR = "dir"
+    SYMLINK = "symlink"
+    OTHER = "other"
+    NONE = "none"
+
+
+class FileOperation(str, Enum):
+    """Kind of access checked against the project access policy."""
+
+    READ = "read"
+    WRITE = "write"
+    DELETE = "delete"
+
+
+class Ecosystem(str, Enum):
+    """Build ecosystem detected from a manifest file."""
+
+    CARGO = "cargo"
+    NPM = "npm"
+    PYTHON = "python"
+    GO = "go"
+    MAVEN = "maven"
+    GRADLE = "gradle"
+    RUBY = "ruby"
+
+
 class FileContent(BaseModel):
     """Content of a file with metadata."""
 
@@ -31,6 +61,9 @@ class FileContent(BaseModel):
     size_bytes: int = Field(..., description="File size in bytes")
     encoding: str = Field(default="utf-8", description="File encoding")
     truncated: bool = Field(default=False, description="Whether content was truncated")
+    language: str | None = Field(
+        default=None, description="Detected language id, when requested"
+    )
 
 
 class FileEntry(BaseModel):
@@ -44,6 +77,39 @@ class FileEntry(BaseModel):
     )
 
 
+class LanguageInfo(BaseModel):
+    """Detected language of a file."""
+
+    path: str = Field(..., description="Path as requested")
+    language: str = Field(..., description="Language id, or plaintext if unknown")
+    confidence: float = Field(..., description="Detection confidence from 0 to 1")
+
+
+class PathStatus(BaseModel):
+    """What, if anything, exists at a path."""
+
+    path: str = Field(..., description="Path as requested")
+    exists: bool = Field(..., description="Whether any entry exists at the path")
+    kind: PathKind = Field(..., description="Kind of entry, without following symlinks")
+    is_broken_symlink: bool = Field(
+        default=False, description="Whether the entry is a symlink to nothing"
+    )
+
+
+class DiskSpace(BaseModel):
+    """Space on the volume containing a path."""
+
+    path: str = Field(..., description="Path as requested")
+    total_bytes: int = Field(..., description="Total size of the volume")
+    available_bytes: int = Field(..., description="Bytes available to this process")
+    filesystem: str | None = Field(
+        default=None, description="Filesystem type, where detectable"
+    )
+    mount_point: str | None = Field(
+        default=None, description="Mount point of the volume, where detectable"
+    )
+
+
 class DirectoryListing(BaseModel):
     """Contents of a directory."""
 
@@ -58,10 +124,16 @@ class SearchMatch(BaseModel):
     """A single search match."""
 
     path: str = Field(..., description="Relative path to file")
-    line_number: int = Field(..., description="Line number (1-indexed)")
+    line_number: int = Field(
+        ..., description="Line number (1-indexed; 0 for file name matches)"
+    )
     line_content: str = Field(..., description="Content of matching line")
-    match_start: int = Field(..., description="Start position of match in line")
-    match_end: int = Field(..., description="End position of match in line")
+    match_start: int = Field(
+        ..., description="Character offset of match start in line_content"
+    )
+    match_end: int = Field(
+        ..., description="Character offset of match end in line_content"
+    )
 
 
 class SearchResult(BaseModel):
@@ -74,6 +146,148 @@ class SearchResult(BaseModel):
     truncated: bool = Field(default=False, description="Whether results were truncated")
 
 
+class DirectorySize(BaseModel):
+    """Recursive size totals for a directory."""
+
+    path: str = Field(..., description="Relative path of directory")
+    size_bytes: int = Field(default=0, description="Total size of all files below")
+    file_count: int = Field(default=0, description="Total file count below")
+    errors: int = Field(default=0, description="Entries below that could not be read")
+    children: list[DirectorySize] = Field(
+        default_factory=list,
+        description="Subdirectories within the requested depth, largest first",
+    )
+
+
+class RecentFile(BaseModel):
+    """A file modified within the requested window."""
+
+    path: str = Field(..., description="Relative path from project root")
+    size_bytes: int = Field(..., description="File size in bytes")
+    modified_at: datetime = Field(..., description="Last modification time (UTC)")
+
+
+class RecentFiles(BaseModel):
+    """Recently modified files, newest first."""
+
+    path: str = Field(..., description="Relative path of searched directory")
+    since: datetime = Field(..., description="Modification time cutoff (UTC)")
+    files: list[RecentFile] = Field(default_factory=list)
+    truncated: bool = Field(default=False, description="Whether results were truncated")
+    cancelled: bool = Field(
+        default=False, description="Whether the walk was cancelled before finishing"
+    )
+
+
+class FuzzyMatch(BaseModel):
+    """A file path matched by a fuzzy query."""
+
+    path: str = Field(..., description="Relative path from project root")
+    score: int = Field(..., description="Match quality, higher is better")
+    positions: list[int] = Field(
+        default_factory=list, description="Indexes of matched characters in path"
+    )
+
+
+class FuzzyFindResult(BaseModel):
+    """Best fuzzy matches for a query."""
+
+    query: str = Field(..., description="Query used")
+    matches: list[FuzzyMatch] = Field(default_factory=list)
+    indexed_files: int = Field(default=0, description="Number of files considered")
+    truncated: bool = Field(default=False, description="Whether results were truncated")
+
+
+class DuplicateGroup(BaseModel):
+    """Files with identical content."""
+
+    size_bytes: int = Field(..., description="Size of each file in the group")
+    sha256: str = Field(..., description="Content hash shared by the group")
+    keeper: str = Field(..., description="Copy to keep (oldest, then shortest path)")
+    duplicates: list[str] = Field(
+        default_factory=list, description="Other copies that could be removed"
+    )
+
+
+class DuplicateReport(BaseModel):
+    """Duplicate files found under a directory."""
+
+    path: str = Field(..., description="Relative path of searched directory")
+    groups: list[DuplicateGroup] = Field(default_factory=list)
+    reclaimable_bytes: int = Field(
+        default=0, description="Bytes freed by removing every non-keeper copy"
+    )
+    files_scanned: int = Field(default=0, description="Number of files considered")
+    cancelled: bool = Field(
+        default=False, description="Whether the scan was cancelled before finishing"
+    )
+
+
+class EcosystemInfo(BaseModel):
+    """Metadata parsed from one project manifest."""
+
+    ecosystem: Ecosystem = Field(..., description="Detected ecosystem")
+    manifest: str = Field(..., description="Relative path to the manifest")
+    name: str | None = Field(default=None, description="Package or module name")
+    version: str | None = Field(default=None, description="Declared version")
+    scripts: dict[str, str] = Field(
+        default_factory=dict, description="Named scripts or entry points"
+    )
+    targets: list[str] = Field(
+        default_factory=list, description="Build targets, e.g. Cargo binaries"
+    )
+    members: list[str] = Field(
+        default_factory=list, description="Workspace member globs or paths"
+    )
+
+
+class ProjectInfo(BaseModel):
+    """Ecosystems detected in a directory."""
+
+    path: str = Field(..., description="Relative path of inspected directory")
+    ecosystems: list[EcosystemInfo] = Field(default_factory=list)
+    warnings: list[str] = Field(
+        default_factory=list, description="Manifests that could not be parsed"
+    )
+
+
+class TokenEstimate(BaseModel):
+    """Estimated token count for one file."""
+
+    path: str = Field(..., description="Relative path from project root")
+    tokens: int | None = Field(
+        default=None, description="Estimated tokens (None if skipped)"
+    )
+    skipped: str | None = Field(
+        default=None, description="Why the file was not counted, if it wasn't"
+    )
+
+
+class TokenReport(BaseModel):
+    """Token estimates for a set of files."""
+
+    method: str = Field(..., description="How the estimates were computed")
+    files: list[TokenEstimate] = Field(default_factory=list)
+    total_tokens: int = Field(default=0, description="Sum over counted files")
+
+
+class PolicyCheck(BaseModel):
+    """Whether path validation and the access policy permit an operation."""
+
+    path: str = Field(..., description="Path as requested")
+    operation: FileOperation = Field(..., description="Operation checked")
+    allowed: bool = Field(..., description="Whether the operation is permitted")
+    reason: str | None = Field(
+        default=None, description="Why the operation is not permitted"
+    )
+    rule: str | None = Field(
+        default=None, description="Policy rule that denies the operation"
+    )
+    line: int | None = Field(
+        default=None, description="Line of the rule in the policy file"
+    )
+
+
 class ProjectStructure(BaseModel):
     """Overview of project structure."""
 
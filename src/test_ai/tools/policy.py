@@ -0,0 +1,197 @@
+"""Per-project access policy declared in a .gorgonpolicy file.
+
+The file uses gitignore pattern syntax, one rule per line:
+
+    # Deny all access to the secrets, except their README
+    infra/secrets/*
+    !infra/secrets/README.md
+    # Readable, but not writable or deletable
+    ro:migrations/**
+
+The last matching rule wins, and a negated rule lifts earlier
+restrictions. As with gitignore, a rule on a directory itself (such as
+infra/secrets/) covers everything inside it and cannot be lifted for its
+contents; deny the directory's entries with /* to allow exceptions.
+Writing or deleting a directory is denied if any entry inside it is
+protected. The policy file itself is always read-only.
+"""
+
+from __future__ import annotations
+
+import os
+from dataclasses import dataclass
+from pathlib import Path
+
+from test_ai.tools.ignore import IgnoreRule, parse_ignore_lines
+from test_ai.tools.models import FileOperation
+
+POLICY_FILENAME = ".gorgonpolicy"
+READ_ONLY_PREFIX = "ro:"
+
+
+@dataclass(frozen=True)
+class PolicyRule:
+    """A single access policy rule."""
+
+    rule: IgnoreRule
+    read_only: bool
+    line: int
+
+    @property
+    def pattern(self) -> str:
+        """The rule as written in the policy file."""
+        prefix = READ_ONLY_PREFIX if self.read_only else ""
+        return prefix + self.rule.pattern
+
+    def blocks(self, operation: FileOperation) -> bool:
+        """Check whether this rule forbids an operation."""
+        return not self.read_only or operation != FileOperation.READ
+
+
+def parse_policy_lines(lines: list[str]) -> list[PolicyRule]:
+    """Parse .gorgonpolicy lines into rules, skipping blanks and comments."""
+    rules: list[PolicyRule] = []
+
+    for line_number, raw in enumerate(lines, start=1):
+        line = raw.strip()
+        read_only = line.startswith(READ_ONLY_PREFIX)
+        if read_only:
+            line = line[len(READ_ONLY_PREFIX) :].lstrip()
+
+        for rule in parse_ignore_lines([line]):
+            rules.append(PolicyRule(rule=rule, read_only=read_only, line=line_number))
+
+    return rules
+
+
+# Keeps the agent from rewriting its own restrictions
+_SELF_PROTECTION = PolicyRule(
+    rule=parse_ignore_lines(["/" + POLICY_FILENAME])[0], read_only=True, line=0
+)
+
+
+class AccessPolicy:
+    """Evaluates a project's .gorgonpolicy rules.
+
+    check() uses the rules from the last refresh(), which re-reads the
+    policy file only when its modification time has changed. Refreshing
+    once per operation picks up edits without a restart, and walks that
+    check every entry do not stat the file for each one.
+    """
+
+    def __init__(self, project_root: str | Path):
+        """Initialize the policy.
+
+        Args:
+            project_root: Directory containing the policy file.
+        """
+        self.policy_file = Path(project_root) / POLICY_FILENAME
+        self._rules: list[PolicyRule] = []
+        self._loaded_mtime: int | None = None
+        self.refresh()
+
+    @property
+    def rules(self) -> list[PolicyRule]:
+        """Rules loaded by the last refresh."""
+        return self._rules
+
+    def refresh(self) -> None:
+        """Reload the policy file if it changed since the last refresh."""
+        try:
+            mtime = self.policy_file.stat().st_mtime_ns
+        except OSError:
+            mtime = None
+
+        if mtime != self._loaded_mtime:
+            self._rules = self._load() if mtime is not None else []
+            self._loaded_mtime = mtime
+
+    def check(
+        self,
+        rel_path: str,
+        operation: FileOperation,
+        is_dir: bool = False,
+    ) -> PolicyRule | None:
+        """Find the rule that forbids an operation on a path, if any.
+
+        Writing or deleting a directory also affects its contents, so for
+        those operations every entry below the directory is checked too.
+
+        Args:
+            rel_path: Path relative to the project root.
+            operation: Operation to check.
+            is_dir: Whether the path is a directory.
+
+        Returns:
+            The blocking rule, or None if the operation is allowed.
+        """
+        normalized = rel_path.replace("\\", "/").strip("/")
+        if normalized == ".":
+            normalized = ""
+
+        if normalized:
+            blocking = self._check_path(normalized, operation, is_dir)
+            if blocking is not None:
+                return blocking
+
+        if is_dir and operation != FileOperation.READ:
+            return self._check_contents(normalized, operation)
+        return None
+
+    def _check_path(
+        self,
+        normalized: str,
+        operation: FileOperation,
+        is_dir: bool,
+    ) -> PolicyRule | None:
+        """Check a single path and its ancestors, preferring deny over ro."""
+        rules = [*self._rules, _SELF_PROTECTION]
+        parts = normalized.split("/")
+
+        blocking: PolicyRule | None = None
+        for depth in range(1, len(parts) + 1):
+            level_is_dir = is_dir or depth < len(parts)
+            effective = _last_match(rules, "/".join(parts[:depth]), level_is_dir)
+            if effective is None or not effective.blocks(operation):
+                continue
+            if not effective.read_only:
+                return effective
+            blocking = blocking or effective
+
+        return blocking
+
+    def _check_contents(
+        self,
+        rel_dir: str,
+        operation: FileOperation,
+    ) -> PolicyRule | None:
+        """Return the first rule protecting an entry below a directory."""
+        base = self.policy_file.parent
+        for root, dirs, files in os.walk(base / rel_dir):
+            rel_root = Path(root).relative_to(base).as_posix()
+            prefix = "" if rel_root == "." else rel_root + "/"
+            for names, entry_is_dir in ((dirs, True), (files, False)):
+                for name in names:
+                    rule = self._check_path(prefix + name, operation, entry_is_dir)
+                    if rule is not None:
+                        return rule
+        return None
+
+    def _load(self) -> list[PolicyRule]:
+        """Parse the policy file, treating an unreadable file as empty."""
+        try:
+            text = self.policy_file.read_text(encoding="utf-8", errors="replace")
+        except OSError:
+            return []
+        return parse_policy_lines(text.splitlines())
+
+
+def _last_match(
+    rules: list[PolicyRule], rel_path: str, is_dir: bool
+) -> PolicyRule | None:
+    """Return the last rule matching a path, or None if it ends up allowed."""
+    effective: PolicyRule | None = None
+    for rule in rules:
+        if rule.rule.matches(rel_path, is_dir):
+            effective = None if rule.rule.negated else rule
+    return effective
@@ -0,0 +1,169 @@
+"""Project type detection from manifest files.
+
+Looks for well-known manifests (Cargo.toml, package.json, pyproject.toml,
+go.mod, ...) in a directory and extracts name, version, scripts, and
+workspace layout. A mixed repository reports every ecosystem found.
+Malformed manifests are reported as warnings rather than failing detection.
+"""
+
+from __future__ import annotations
+
+import json
+import tomllib
+from collections.abc import Callable
+from pathlib import Path
+from typing import Any
+
+from test_ai.tools.models import Ecosystem, EcosystemInfo, ProjectInfo
+
+
+def detect_project(directory: Path, rel_path: str = ".") -> ProjectInfo:
+    """Detect the ecosystems declared in a directory.
+
+    Args:
+        directory: Directory to inspect.
+        rel_path: Path of the directory as reported in the result.
+
+    Returns:
+        ProjectInfo listing each detected ecosystem.
+    """
+    info = ProjectInfo(path=rel_path)
+
+    for filename, ecosystem, parser in _MANIFESTS:
+        manifest = directory / filename
+        if not manifest.is_file():
+            continue
+
+        manifest_rel = str(Path(rel_path) / filename) if rel_path != "." else filename
+        entry = EcosystemInfo(ecosystem=ecosystem, manifest=manifest_rel)
+        if parser is not None:
+            try:
+                parser(manifest, entry)
+            except (OSError, ValueError, TypeError, AttributeError) as e:
+                info.warnings.append(f"{manifest_rel}: {e}")
+        info.ecosystems.append(entry)
+
+    return info
+
+
+def _parse_cargo(manifest: Path, entry: EcosystemInfo) -> None:
+    """Read package, binary targets, and workspace members from Cargo.toml."""
+    data = tomllib.loads(manifest.read_text(encoding="utf-8"))
+
+    package = _table(data.get("package"), "package")
+    entry.name = _string(package.get("name"), "package.name")
+    entry.version = _version(package.get("version"), "package.version")
+
+    bins = data.get("bin", [])
+    if not isinstance(bins, list):
+        raise ValueError("bin must be an array of tables")
+    for b in bins:
+        name = _string(_table(b, "bin").get("name"), "bin.name")
+        if name is not None:
+            entry.targets.append(name)
+
+    workspace = _table(data.get("workspace"), "workspace")
+    entry.members = _string_list(workspace.get("members"), "workspace.members")
+
+
+def _parse_package_json(manifest: Path, entry: EcosystemInfo) -> None:
+    """Read name, version, scripts, and workspaces from package.json."""
+    data = json.loads(manifest.read_text(encoding="utf-8"))
+    if not isinstance(data, dict):
+        raise ValueError("expected a JSON object")
+
+    entry.name = _string(data.get("name"), "name")
+    entry.version = _string(data.get("version"), "version")
+    entry.scripts = _string_map(data.get("scripts"), "scripts")
+
+    workspaces = data.get("workspaces")
+    if isinstance(workspaces, dict):
+        workspaces = workspaces.get("packages")
+    entry.members = _string_list(workspaces, "workspaces")
+
+
+def _parse_pyproject(manifest: Path, entry: EcosystemInfo) -> None:
+    """Read PEP 621 metadata, falling back to [tool.poetry]."""
+    data = tomllib.loads(manifest.read_text(encoding="utf-8"))
+
+    project = _table(data.get("project"), "project")
+    tool = _table(data.get("tool"), "tool")
+    poetry = _table(tool.get("poetry"), "tool.poetry")
+    entry.name = _string(project.get("name") or poetry.get("name"), "name")
+    entry.version = _version(project.get("version") or poetry.get("version"), "version")
+
+    scripts = _table(project.get("scripts") or poetry.get("scripts"), "scripts")
+    entry.scripts = {k: _script_target(v) for k, v in scripts.items()}
+
+
+def _parse_go_mod(manifest: Path, entry: EcosystemInfo) -> None:
+    """Read the module path from go.mod."""
+    for line in manifest.read_text(encoding="utf-8").splitlines():
+        parts = line.split()
+        if len(parts) >= 2 and parts[0] == "module":
+            entry.name = parts[1].strip('"')
+            return
+
+
+def _table(value: Any, field: str) -> dict[str, Any]:
+    """Require a table/object, treating a missing one as empty."""
+    if value is None:
+        return {}
+    if not isinstance(value, dict):
+        raise ValueError(f"{field} must be a table")
+    return value
+
+
+def _string(value: Any, field: str) -> str | None:
+    """Require a string, treating a missing value as None."""
+    if value is None or isinstance(value, str):
+        return value
+    raise ValueError(f"{field} must be a string")
+
+
+def _version(value: Any, field: str) -> str | None:
+    """Like _string, but inherited/dynamic version tables become None."""
+    if isinstance(value, dict):
+        return None
+    return _string(value, field)
+
+
+def _string_list(value: Any, field: str) -> list[str]:
+    """Require a list of strings, treating a missing list as empty."""
+    if value is None:
+        return []
+    if not isinstance(value, list) or not all(isinstance(v, str) for v in value):
+        raise ValueError(f"{field} must be a list of strings")
+    return list(value)
+
+
+def _string_map(value: Any, field: str) -> dict[str, str]:
+    """Require a string-to-string mapping, treating a missing one as empty."""
+    if value is None:
+        return {}
+    if not isinstance(value, dict) or not all(
+        isinstance(v, str) for v in value.values()
+    ):
+        raise ValueError(f"{field} must map names to strings")
+    return dict(value)
+
+
+def _script_target(value: Any) -> str:
+    """Render a script entry, which Poetry allows to be a table."""
+    if isinstance(value, dict):
+        return str(value.get("callable") or value.get("reference") or value)
+    return str(value)
+
+
+_MANIFESTS: list[
+    tuple[str, Ecosystem, Callable[[Path, EcosystemInfo], None] | None]
+] = [
+    ("Cargo.toml", Ecosystem.CARGO, _parse_cargo),
+    ("package.json", Ecosystem.NPM, _parse_package_json),
+    ("pyproject.toml", Ecosystem.PYTHON, _parse_pyproject),
+    ("go.mod", Ecosystem.GO, _parse_go_mod),
+    ("pom.xml", Ecosystem.MAVEN, None),
+    ("build.gradle", Ecosystem.GRADLE, None),
+    ("build.gradle.kts", Ecosystem.GRADLE, None),
+    ("Gemfile", Ecosystem.RUBY, None),
+]
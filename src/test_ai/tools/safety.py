@@ -5,6 +5,7 @@ Security-first design:
 - No symlink traversal outside project bounds
 - Excluded patterns for sensitive directories
 - File size limits to prevent memory issues
+- Per-project access policy from .gorgonpolicy (see policy.py)
 """
 
 from __future__ import annotations
@@ -14,6 +15,9 @@ import re
 from pathlib import Path
 from typing import TYPE_CHECKING
 
+from test_ai.tools.models import FileOperation, PolicyCheck
+from test_ai.tools.policy import POLICY_FILENAME, AccessPolicy, PolicyRule
+
 if TYPE_CHECKING:
     pass
 
@@ -24,6 +28,21 @@ class SecurityError(Exception):
     pass
 
 
+class PolicyViolation(SecurityError):
+    """Raised when the project's .gorgonpolicy forbids an operation."""
+
+    code = "POLICY_DENIED"
+
+    def __init__(self, path: str | Path, operation: FileOperation, rule: PolicyRule):
+        self.path = str(path)
+        self.operation = operation
+        self.rule = rule
+        super().__init__(
+            f"{operation.value.capitalize()} denied by {POLICY_FILENAME} rule "
+            f"'{rule.pattern}' (line {rule.line}): {path}"
+        )
+
+
 # Patterns to exclude from file operations
 DEFAULT_EXCLUDE_PATTERNS: list[str] = [
     r"^\.git(/|$)",
@@ -70,6 +89,7 @@ class PathValidator:
         allowed_paths: list[str] | None = None,
         exclude_patterns: list[str] | None = None,
         max_file_size: int = DEFAULT_MAX_FILE_SIZE,
+        use_policy: bool = True,
     ):
         """Initialize the path validator.
 
@@ -81,6 +101,7 @@ class PathValidator:
             exclude_patterns: Regex patterns for paths to exclude. Defaults to
                               DEFAULT_EXCLUDE_PATTERNS.
             max_file_size: Maximum file size in bytes for read operations.
+            use_policy: Whether to apply the project's .gorgonpolicy.
         """
         self.project_path = Path(project_path).resolve()
         self.allowed_paths = [Path(p).resolve() for p in (allowed_paths or [])]
@@ -88,6 +109,7 @@ class PathValidator:
             re.compile(p) for p in (exclude_patterns or DEFAULT_EXCLUDE_PATTERNS)
         ]
         self.max_file_size = max_file_size
+        self.policy = AccessPolicy(self.project_path) if use_policy else None
 
         # Validate project path exists
         if not self.project_path.is_dir():
@@ -95,16 +117,22 @@ class PathValidator:
                 f"Project path does not exist or is not a directory: {project_path}"
             )
 
-    def validate_path(self, path: str | Path) -> Path:
+    def validate_path(
+        self,
+        path: str | Path,
+        operation: FileOperation = FileOperation.READ,
+    ) -> Path:
         """Validate a path and return its resolved form.
 
         Args:
             path: Path to validate (relative or absolute).
+            operation: Operation the path will be used for.
 
         Returns:
             Resolved absolute path if valid.
 
         Raises:
+            PolicyViolation: If the project policy forbids the operation.
             SecurityError: If path fails validation.
         """
         # Convert to Path object
@@ -126,6 +154,8 @@ class PathValidator:
         if rel_path and self._matches_exclude_pattern(rel_path):
             raise SecurityError(f"Path matches excluded pattern: {path}")
 
+        self.enforce_policy(path, rel_path, operation, resolved.is_dir())
+
         return resolved
 
     def validate_file_for_read(self, path: str | Path) -> Path:
@@ -211,8 +241,85 @@ class PathValidator:
         if rel_path and self._matches_exclude_pattern(rel_path):
             raise SecurityError(f"Path matches excluded pattern: {path}")
 
+        self.enforce_policy(path, rel_path, FileOperation.WRITE, resolved.is_dir())
+
         return resolved
 
+    def policy_check(
+        self,
+        path: str | Path,
+        operation: FileOperation = FileOperation.READ,
+    ) -> PolicyCheck:
+        """Check whether an operation on a path would pass validation.
+
+        Applies the same bounds, exclude, and .gorgonpolicy checks as
+        validate_path, but reports the outcome instead of raising.
+
+        Args:
+            path: Path to check (relative or absolute).
+            operation: Operation to check.
+
+        Returns:
+            PolicyCheck with the reason and, for policy denials, the rule.
+        """
+        try:
+            self.validate_path(path, operation)
+        except PolicyViolation as e:
+            return PolicyCheck(
+                path=str(path),
+                operation=operation,
+                allowed=False,
+                reason=str(e),
+                rule=e.rule.pattern,
+                line=e.rule.line,
+            )
+        except SecurityError as e:
+            return PolicyCheck(
+                path=str(path), operation=operation, allowed=False, reason=str(e)
+            )
+        return PolicyCheck(path=str(path), operation=operation, allowed=True)
+
+    def is_denied(self, rel_path: str, is_dir: bool = False) -> bool:
+        """Check if the project policy forbids reading a path, without raising.
+
+        Uses the rules from the last refresh_policy() call, so recursive
+        walks can check every entry without re-reading the policy file.
+
+        Args:
+            rel_path: Path relative to the project root.
+            is_dir: Whether the path is a directory.
+
+        Returns:
+            True if the path should be hidden from the agent.
+        """
+        return self._policy_rule(rel_path, FileOperation.READ, is_dir) is not None
+
+    def is_readable_target(self, resolved: Path) -> bool:
+        """Check a resolved path against bounds, excludes, and policy.
+
+        Unlike validate_path this neither raises nor reloads the policy
+        file, so recursive walks can vet symlink targets cheaply.
+
+        Args:
+            resolved: Absolute, resolved path.
+
+        Returns:
+            True if the path may be read.
+        """
+        if not self._is_within_allowed_paths(resolved):
+            return False
+        rel_path = self._get_relative_path(resolved)
+        if rel_path is None:
+            return True
+        if rel_path and self._matches_exclude_pattern(rel_path):
+            return False
+        return not self.is_denied(rel_path, resolved.is_dir())
+
+    def refresh_policy(self) -> None:
+        """Reload .gorgonpolicy if it changed; call once per operation."""
+        if self.policy is not None:
+            self.policy.refresh()
+
     def is_excluded(self, path: str | Path) -> bool:
         """Check if a path matches exclusion patterns without raising.
 
@@ -251,6 +358,40 @@ class PathValidator:
         except ValueError:
             return None
 
+    def _policy_rule(
+        self,
+        rel_path: str | None,
+        operation: FileOperation,
+        is_dir: bool,
+    ) -> PolicyRule | None:
+        """Return the policy rule forbidding an operation, if any."""
+        if self.policy is None or rel_path is None:
+            return None
+        return self.policy.check(rel_path.replace(os.sep, "/"), operation, is_dir)
+
+    def enforce_policy(
+        self,
+        path: str | Path,
+        rel_path: str | None,
+        operation: FileOperation,
+        is_dir: bool,
+    ) -> None:
+        """Raise PolicyViolation if the project policy forbids an operation.
+
+        Args:
+            path: Path as given by the caller, used in the error message.
+            rel_path: Path relative to the project root, or None if outside.
+            operation: Operation to check.
+            is_dir: Whether the path is a directory.
+
+        Raises:
+            PolicyViolation: If a policy rule forbids the operation.
+        """
+        self.refresh_policy()
+        rule = self._policy_rule(rel_path, operation, is_dir)
+        if rule is not None:
+            raise PolicyViolation(path, operation, rule)
+
     def _matches_exclude_pattern(self, rel_path: str) -> bool:
         """Check if relative path matches any exclude pattern."""
         # Normalize path separators
@@ -0,0 +1,72 @@
+"""Approximate token counting for context-window budgeting.
+
+Counts are estimates from a characters-per-token ratio, calibrated per
+model family, rather than a real tokenizer. Files are streamed in chunks
+so large files are never held in memory, and binary files are skipped.
+"""
+
+from __future__ import annotations
+
+import codecs
+import math
+from pathlib import Path
+
+DEFAULT_CHARS_PER_TOKEN = 4.0
+
+# Rough ratios for English prose and source code; matched by prefix
+MODEL_CHARS_PER_TOKEN: dict[str, float] = {
+    "claude": 3.5,
+    "gpt": 4.0,
+    "llama": 3.8,
+}
+
+# Same sniff window git uses to decide a file is binary
+BINARY_SNIFF_BYTES = 8000
+READ_CHUNK_BYTES = 64 * 1024
+
+
+def chars_per_token(model_hint: str | None = None) -> float:
+    """Look up the characters-per-token ratio for a model family."""
+    if model_hint:
+        hint = model_hint.lower()
+        for prefix, ratio in MODEL_CHARS_PER_TOKEN.items():
+            if hint.startswith(prefix):
+                return ratio
+    return DEFAULT_CHARS_PER_TOKEN
+
+
+def estimate_method(ratio: float) -> str:
+    """Describe how estimates were produced, for labeling in the UI."""
+    return f"chars_per_token:{ratio:g}"
+
+
+def tokens_for_chars(chars: int, ratio: float) -> int:
+    """Convert a character count into an estimated token count."""
+    return math.ceil(chars / ratio)
+
+
+def count_chars(path: Path) -> int | None:
+    """Count decoded characters in a text file without reading it whole.
+
+    Args:
+        path: File to count.
+
+    Returns:
+        Number of characters, or None if the file looks binary.
+
+    Raises:
+        OSError: If the file cannot be read.
+    """
+    decoder = codecs.getincrementaldecoder("utf-8")(errors="replace")
+    chars = 0
+    sniffed = False
+
+    with path.open("rb") as f:
+        while chunk := f.read(READ_CHUNK_BYTES):
+            if not sniffed:
+                if b"\0" in chunk[:BINARY_SNIFF_BYTES]:
+                    return None
+                sniffed = True
+            chars += len(decoder.decode(chunk))
+
+    return chars + len(decoder.decode(b"", final=True))
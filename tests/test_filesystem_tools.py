@@ -1,12 +1,19 @@
 """Tests for filesystem tools module."""
 
+import os
+import shutil
+import threading
+import time
+from datetime import datetime, timedelta, timezone
 from pathlib import Path
+from unittest.mock import patch
 
 import pytest
 
 from test_ai.tools.safety import PathValidator, SecurityError
 from test_ai.tools.models import (
     EditProposal,
+    PathKind,
     ProposalStatus,
 )
 from test_ai.tools.filesystem import FilesystemTools
@@ -463,3 +470,660 @@ class TestFilesystemToolsEdgeCases:
 
         structure = tools.get_structure()
         assert "empty/" in structure.tree
+
+
+class TestRecentFiles:
+    """Tests for recent_files."""
+
+    @pytest.fixture
+    def tools(self, tmp_path: Path) -> FilesystemTools:
+        """Create FilesystemTools with test directory."""
+        return FilesystemTools(PathValidator(tmp_path))
+
+    def test_recent_files_newest_first(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that recent files are sorted newest first with metadata."""
+        now = time.time()
+        for name, age in [("old.py", 300), ("new.py", 10), ("mid.py", 120)]:
+            f = tmp_path / name
+            f.write_text("x" * 5)
+            os.utime(f, (now - age, now - age))
+
+        result = tools.recent_files()
+
+        assert [f.path for f in result.files] == ["new.py", "mid.py", "old.py"]
+        assert result.files[0].size_bytes == 5
+        assert result.truncated is False
+
+    def test_recent_files_cutoff(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that files older than the cutoff are omitted."""
+        stale = tmp_path / "stale.txt"
+        stale.write_text("a")
+        two_days_ago = time.time() - 2 * 24 * 3600
+        os.utime(stale, (two_days_ago, two_days_ago))
+        (tmp_path / "fresh.txt").write_text("b")
+
+        assert [f.path for f in tools.recent_files().files] == ["fresh.txt"]
+
+        since = datetime.now(timezone.utc) - timedelta(days=3)
+        result = tools.recent_files(since=since)
+        assert {f.path for f in result.files} == {"fresh.txt", "stale.txt"}
+
+    def test_recent_files_naive_since_is_local(
+        self, tmp_path: Path, tools: FilesystemTools
+    ):
+        """Test that a naive cutoff is interpreted as local time."""
+        stale = tmp_path / "stale.txt"
+        stale.write_text("a")
+        two_hours_ago = time.time() - 2 * 3600
+        os.utime(stale, (two_hours_ago, two_hours_ago))
+        (tmp_path / "fresh.txt").write_text("b")
+
+        try:
+            with patch.dict(os.environ, {"TZ": "Asia/Tokyo"}):
+                time.tzset()
+                since = datetime.now() - timedelta(hours=1)
+                result = tools.recent_files(since=since)
+        finally:
+            time.tzset()
+
+        assert [f.path for f in result.files] == ["fresh.txt"]
+        assert result.since.tzinfo == timezone.utc
+
+    def test_recent_files_skips_excluded(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that excluded directories are not reported."""
+        (tmp_path / "node_modules" / "pkg").mkdir(parents=True)
+        (tmp_path / "node_modules" / "pkg" / "index.js").write_text("x")
+        (tmp_path / "src").mkdir()
+        (tmp_path / "src" / "main.py").write_text("x")
+
+        result = tools.recent_files()
+
+        assert [f.path for f in result.files] == [os.path.join("src", "main.py")]
+
+    def test_recent_files_limit(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that the limit truncates results."""
+        for i in range(5):
+            (tmp_path / f"f{i}.txt").write_text("x")
+
+        result = tools.recent_files(limit=2)
+
+        assert len(result.files) == 2
+        assert result.truncated is True
+
+    def test_recent_files_cancelled(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that a set cancel event stops the walk."""
+        (tmp_path / "a.txt").write_text("x")
+        cancel = threading.Event()
+        cancel.set()
+
+        result = tools.recent_files(cancel_event=cancel)
+
+        assert result.cancelled is True
+        assert result.files == []
+
+    def test_recent_files_skips_escaping_symlinks(self, tmp_path: Path):
+        """Test that links to files outside the project are not statted."""
+        project = tmp_path / "proj"
+        project.mkdir()
+        (tmp_path / "outside").mkdir()
+        (tmp_path / "outside" / "secret.txt").write_text("s" * 20)
+        (project / "inside.txt").write_text("x")
+        (project / "link.txt").symlink_to(tmp_path / "outside" / "secret.txt")
+        (project / "alias.txt").symlink_to(project / "inside.txt")
+        tools = FilesystemTools(PathValidator(project))
+
+        result = tools.recent_files()
+
+        assert {f.path for f in result.files} == {"inside.txt", "alias.txt"}
+
+
+class TestDirectorySizes:
+    """Tests for directory_sizes."""
+
+    @pytest.fixture
+    def tools(self, tmp_path: Path) -> FilesystemTools:
+        """Create FilesystemTools with test directory."""
+        return FilesystemTools(PathValidator(tmp_path))
+
+    def test_recursive_totals(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that each node carries recursive totals."""
+        (tmp_path / "src" / "pkg").mkdir(parents=True)
+        (tmp_path / "src" / "a.py").write_bytes(b"x" * 10)
+        (tmp_path / "src" / "pkg" / "b.py").write_bytes(b"x" * 20)
+        (tmp_path / "docs").mkdir()
+        (tmp_path / "docs" / "readme.md").write_bytes(b"x" * 5)
+        (tmp_path / "top.txt").write_bytes(b"x" * 1)
+
+        root = tools.directory_sizes()
+
+        assert root.size_bytes == 36
+        assert root.file_count == 4
+        assert [c.path for c in root.children] == ["src", "docs"]
+        assert root.children[0].size_bytes == 30
+        assert root.children[0].file_count == 2
+        # depth=1 stops the tree at top-level children
+        assert root.children[0].children == []
+
+    def test_depth(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that depth controls how many levels are returned."""
+        (tmp_path / "a" / "b" / "c").mkdir(parents=True)
+        (tmp_path / "a" / "b" / "c" / "f.txt").write_bytes(b"x" * 3)
+
+        root = tools.directory_sizes(depth=2)
+
+        level_b = root.children[0].children[0]
+        assert level_b.path == os.path.join("a", "b")
+        assert level_b.size_bytes == 3
+        assert level_b.children == []
+
+    def test_hard_links_counted_once(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that hard-linked files are not double counted."""
+        original = tmp_path / "data.bin"
+        original.write_bytes(b"x" * 100)
+        os.link(original, tmp_path / "data-link.bin")
+
+        root = tools.directory_sizes()
+
+        assert root.size_bytes == 100
+        assert root.file_count == 1
+
+    def test_symlinks_not_followed(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that symlinked directories are not measured."""
+        (tmp_path / "real").mkdir()
+        (tmp_path / "real" / "f.txt").write_bytes(b"x" * 7)
+        (tmp_path / "alias").symlink_to(tmp_path / "real")
+
+        root = tools.directory_sizes()
+
+        assert root.size_bytes == 7
+        assert [c.path for c in root.children] == ["real"]
+
+    def test_excluded_directories_skipped(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that excluded directories do not contribute to totals."""
+        (tmp_path / "node_modules").mkdir()
+        (tmp_path / "node_modules" / "big.js").write_bytes(b"x" * 1000)
+        (tmp_path / "main.py").write_bytes(b"x" * 2)
+
+        root = tools.directory_sizes()
+
+        assert root.size_bytes == 2
+        assert root.children == []
+
+    def test_unreadable_directory_counted_as_error(
+        self, tmp_path: Path, tools: FilesystemTools
+    ):
+        """Test that unreadable directories are reported, not raised."""
+        (tmp_path / "locked").mkdir()
+        (tmp_path / "ok.txt").write_bytes(b"x")
+        real_scandir = os.scandir
+
+        def scandir(path):
+            if Path(path).name == "locked":
+                raise PermissionError("denied")
+            return real_scandir(path)
+
+        with patch("test_ai.tools.filesystem.os.scandir", side_effect=scandir):
+            root = tools.directory_sizes()
+
+        assert root.errors == 1
+        assert root.children[0].errors == 1
+        assert root.file_count == 1
+
+    def test_progress_callback(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that progress is reported per top-level child."""
+        for name in ("a", "b"):
+            (tmp_path / name).mkdir()
+            (tmp_path / name / "f.txt").write_bytes(b"x")
+        reported: list[str] = []
+
+        tools.directory_sizes(progress_callback=lambda node: reported.append(node.path))
+
+        assert sorted(reported) == ["a", "b"]
+
+
+class TestRespectIgnores:
+    """Tests for .gitignore/.gorgonignore handling across tools."""
+
+    @pytest.fixture
+    def tools(self, tmp_path: Path) -> FilesystemTools:
+        """Create a project whose target/ directory is gitignored."""
+        (tmp_path / ".gitignore").write_text("target/\n*.tmp\n")
+        (tmp_path / "target").mkdir()
+        (tmp_path / "target" / "out.rs").write_text("fn needle() {}")
+        (tmp_path / "scratch.tmp").write_text("needle")
+        (tmp_path / "lib.rs").write_text("fn needle() {}")
+        return FilesystemTools(PathValidator(tmp_path))
+
+    def test_search_code(self, tools: FilesystemTools):
+        """Test that search skips ignored files unless asked not to."""
+        assert {m.path for m in tools.search_code("needle").matches} == {"lib.rs"}
+
+        everything = tools.search_code("needle", respect_ignores=False)
+        assert len({m.path for m in everything.matches}) == 3
+
+    def test_list_files(self, tools: FilesystemTools):
+        """Test that recursive listings skip ignored paths."""
+        listing = tools.list_files(".", recursive=True)
+
+        assert {e.path for e in listing.entries} == {".gitignore", "lib.rs"}
+
+    def test_get_structure(self, tools: FilesystemTools):
+        """Test that the tree omits ignored directories."""
+        assert "target/" not in tools.get_structure().tree
+        assert "target/" in tools.get_structure(respect_ignores=False).tree
+
+    def test_glob_and_recent_files(self, tools: FilesystemTools):
+        """Test that glob and recent-file listings skip ignored files."""
+        assert tools.glob_files("**/*.rs") == ["lib.rs"]
+        assert {f.path for f in tools.recent_files().files} == {".gitignore", "lib.rs"}
+
+    def test_find_duplicates(self, tools: FilesystemTools):
+        """Test that duplicates in ignored paths are only found when asked."""
+        assert tools.find_duplicates().groups == []
+
+        (group,) = tools.find_duplicates(respect_ignores=False).groups
+        assert {group.keeper, *group.duplicates} == {"lib.rs", "target/out.rs"}
+
+
+class TestPathKind:
+    """Tests for path_kind."""
+
+    @pytest.fixture
+    def tools(self, tmp_path: Path) -> FilesystemTools:
+        """Create FilesystemTools with test directory."""
+        return FilesystemTools(PathValidator(tmp_path))
+
+    def test_file_and_dir(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that files and directories are distinguished."""
+        (tmp_path / "f.txt").write_text("x")
+        (tmp_path / "d").mkdir()
+
+        assert tools.path_kind("f.txt").kind == PathKind.FILE
+        assert tools.path_kind("d").kind == PathKind.DIR
+        assert tools.path_kind(".").kind == PathKind.DIR
+
+    def test_missing(self, tools: FilesystemTools):
+        """Test that missing paths report kind none."""
+        status = tools.path_kind("nope/missing.txt")
+
+        assert status.exists is False
+        assert status.kind == PathKind.NONE
+
+    def test_symlink(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that a live symlink is reported without following it."""
+        (tmp_path / "target.txt").write_text("x")
+        (tmp_path / "link").symlink_to(tmp_path / "target.txt")
+
+        status = tools.path_kind("link")
+
+        assert status.kind == PathKind.SYMLINK
+        assert status.is_broken_symlink is False
+
+    def test_broken_symlink(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that a dangling symlink still exists."""
+        (tmp_path / "dangling").symlink_to(tmp_path / "gone.txt")
+
+        status = tools.path_kind("dangling")
+
+        assert status.exists is True
+        assert status.kind == PathKind.SYMLINK
+        assert status.is_broken_symlink is True
+
+    def test_other(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that special files report kind other."""
+        os.mkfifo(tmp_path / "pipe")
+
+        assert tools.path_kind("pipe").kind == PathKind.OTHER
+
+    def test_outside_project_blocked(self, tools: FilesystemTools):
+        """Test that paths outside the project are rejected."""
+        with pytest.raises(SecurityError, match="outside allowed directories"):
+            tools.path_kind("../elsewhere")
+
+    def test_excluded_blocked(self, tools: FilesystemTools):
+        """Test that excluded paths are rejected."""
+        with pytest.raises(SecurityError, match="excluded pattern"):
+            tools.path_kind(".env")
+
+
+class TestFuzzyFind:
+    """Tests for fuzzy_find."""
+
+    @pytest.fixture
+    def tools(self, tmp_path: Path) -> FilesystemTools:
+        """Create a small project to search."""
+        (tmp_path / "src" / "tools").mkdir(parents=True)
+        (tmp_path / "src" / "tools" / "filesystem.py").write_text("")
+        (tmp_path / "src" / "tools" / "safety.py").write_text("")
+        (tmp_path / "docs").mkdir()
+        (tmp_path / "docs" / "files.md").write_text("")
+        return FilesystemTools(PathValidator(tmp_path))
+
+    def test_best_match_first(self, tools: FilesystemTools):
+        """Test that results are ranked and carry highlight positions."""
+        result = tools.fuzzy_find("fsys")
+
+        assert result.matches[0].path == os.path.join("src", "tools", "filesystem.py")
+        assert len(result.matches[0].positions) == 4
+        assert result.indexed_files == 3
+
+    def test_limit(self, tools: FilesystemTools):
+        """Test that the limit truncates results."""
+        result = tools.fuzzy_find("s", limit=1)
+
+        assert len(result.matches) == 1
+        assert result.truncated is True
+
+    def test_index_is_cached(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that the index is reused until invalidated or refreshed."""
+        tools.fuzzy_find("x")
+        (tmp_path / "newfile.py").write_text("")
+
+        assert tools.fuzzy_find("newfile").matches == []
+        assert tools.fuzzy_find("newfile", refresh=True).matches
+
+        (tmp_path / "another.py").write_text("")
+        tools.invalidate_file_index()
+        assert tools.fuzzy_find("another").matches
+
+    def test_ignored_files_not_indexed(self, tmp_path: Path):
+        """Test that ignored build artifacts are not candidates."""
+        (tmp_path / ".gitignore").write_text("target/\n")
+        (tmp_path / "target").mkdir()
+        (tmp_path / "target" / "main.o").write_text("")
+        (tmp_path / "main.rs").write_text("")
+        tools = FilesystemTools(PathValidator(tmp_path))
+
+        assert [m.path for m in tools.fuzzy_find("main").matches] == ["main.rs"]
+
+
+class TestFindDuplicates:
+    """Tests for find_duplicates."""
+
+    @pytest.fixture
+    def tools(self, tmp_path: Path) -> FilesystemTools:
+        """Create FilesystemTools with test directory."""
+        return FilesystemTools(PathValidator(tmp_path))
+
+    def test_groups_identical_files(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that identical files are grouped with one keeper."""
+        now = time.time()
+        for name, age in [("orig.txt", 300), ("copy.txt", 10), ("copy2.txt", 5)]:
+            f = tmp_path / name
+            f.write_text("same content")
+            os.utime(f, (now - age, now - age))
+        (tmp_path / "other.txt").write_text("diff content")  # same size, differs
+
+        report = tools.find_duplicates()
+
+        assert len(report.groups) == 1
+        group = report.groups[0]
+        assert group.keeper == "orig.txt"
+        assert sorted(group.duplicates) == ["copy.txt", "copy2.txt"]
+        assert report.reclaimable_bytes == 2 * len("same content")
+
+    def test_same_prefix_different_tail(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that files differing only after the partial hash are distinct."""
+        prefix = b"x" * 8192
+        (tmp_path / "a.bin").write_bytes(prefix + b"a")
+        (tmp_path / "b.bin").write_bytes(prefix + b"b")
+
+        assert tools.find_duplicates().groups == []
+
+    def test_min_size(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that files below min_size are skipped."""
+        (tmp_path / "a.txt").write_text("tiny")
+        (tmp_path / "b.txt").write_text("tiny")
+
+        assert tools.find_duplicates(min_size=100).groups == []
+        assert len(tools.find_duplicates().groups) == 1
+
+    def test_hard_links_not_duplicates(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that hard links to one file are not reported."""
+        (tmp_path / "a.txt").write_text("content")
+        os.link(tmp_path / "a.txt", tmp_path / "b.txt")
+
+        assert tools.find_duplicates().groups == []
+
+    def test_ignored_files_skipped(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that ignored files are not considered."""
+        (tmp_path / ".gitignore").write_text("dist/\n")
+        (tmp_path / "dist").mkdir()
+        (tmp_path / "dist" / "bundle.js").write_text("code")
+        (tmp_path / "bundle.js").write_text("code")
+
+        assert tools.find_duplicates().groups == []
+
+    def test_progress_and_cancel(self, tmp_path: Path, tools: FilesystemTools):
+        """Test progress reporting and cancellation."""
+        for name in ("a.txt", "b.txt"):
+            (tmp_path / name).write_text("dup")
+        progress: list[tuple[int, int]] = []
+
+        tools.find_duplicates(progress_callback=lambda d, t: progress.append((d, t)))
+        assert progress[-1] == (6, 6)
+
+        cancel = threading.Event()
+        cancel.set()
+        report = tools.find_duplicates(cancel_event=cancel)
+        assert report.cancelled is True
+        assert report.groups == []
+
+
+class TestDiskSpace:
+    """Tests for disk_space."""
+
+    @pytest.fixture
+    def tools(self, tmp_path: Path) -> FilesystemTools:
+        """Create FilesystemTools with test directory."""
+        return FilesystemTools(PathValidator(tmp_path))
+
+    def test_reports_volume(self, tmp_path: Path, tools: FilesystemTools):
+        """Test that totals match the OS view of the volume."""
+        space = tools.disk_space()
+        expected = shutil.disk_usage(tmp_path)
+
+        assert space.total_bytes == expected.total
+        assert 0 < space.available_bytes <= space.total_bytes
+        assert space.mount_point is not None
+        assert os.path.ismount(space.mount_point)
+
+    def test_missing_path_uses_ancestor(self, tools: FilesystemTools):
+        """Test that a not-yet-created path resolves to an existing ancestor."""
+        space = tools.disk_space("not/created/yet")
+
+        assert space.path == "not/created/yet"
+        assert space.total_bytes == tools.disk_space().total_bytes
+
+    def test_outside_project_blocked(self, tools: FilesystemTools):
+        """Test that paths outside the project are rejected."""
+        with pytest.raises(SecurityError, match="outside allowed directories"):
+            tools.disk_space("../../")
+
+
+class TestEstimateTokens:
+    """Tests for estimate_tokens and estimate_tokens_tree."""
+
+    @pytest.fixture
+    def tools(self, tmp_path: Path) -> FilesystemTools:
+        """Create a project with text, binary, and ignored files."""
+        (tmp_path / "src").mkdir()
+        (tmp_path / "src" / "main.py").write_text("x" * 400)
+        (tmp_path / "src" / "util.py").write_text("é" * 40)
+        (tmp_path / "README.md").write_text("y" * 80)
+        (tmp_path / "logo.png").write_bytes(b"\x89PNG\x00\x00" * 10)
+        (tmp_path / ".gitignore").write_text("build/\n")
+        (tmp_path / "build").mkdir()
+        (tmp_path / "build" / "out.py").write_text("z" * 4000)
+        return FilesystemTools(PathValidator(tmp_path))
+
+    def test_counts_characters_not_bytes(self, tools: FilesystemTools):
+        """Test per-file estimates use decoded characters."""
+        report = tools.estimate_tokens(["src/main.py", "src/util.py"])
+
+        assert [f.tokens for f in report.files] == [100, 10]
+        assert report.total_tokens == 110
+        assert report.method == "chars_per_token:4"
+
+    def test_model_hint_changes_ratio(self, tools: FilesystemTools):
+        """Test that a model hint selects its calibration."""
+        report = tools.estimate_tokens(["src/main.py"], model_hint="claude-sonnet")
+
+        assert report.method == "chars_per_token:3.5"
+        assert report.total_tokens == 115
+
+    def test_skips_binary_and_missing(self, tools: FilesystemTools):
+        """Test that binary and missing files are reported as skipped."""
+        report = tools.estimate_tokens(["logo.png", "gone.txt"])
+
+        assert [f.skipped for f in report.files] == ["binary", "not a file"]
+        assert report.total_tokens == 0
+
+    def test_streams_large_files(self, tmp_path: Path, tools: FilesystemTools):
+        """Test files larger than the read limit are still counted."""
+        (tmp_path / "big.txt").write_text("a" * (11 * 1024 * 1024))
+
+        report = tools.estimate_tokens(["big.txt"])
+
+        assert report.total_tokens == 11 * 1024 * 1024 // 4
+
+    def test_tree_respects_ignores_and_globs(self, tools: FilesystemTools):
+        """Test tree estimation with ignore files and include/exclude globs."""
+        report = tools.estimate_tokens_tree()
+        assert {f.path for f in report.files} == {
+            ".gitignore",
+            "README.md",
+            "logo.png",
+            "src/main.py",
+            "src/util.py",
+        }
+
+        report = tools.estimate_tokens_tree(
+            include_globs=["*.py", "*.md"], exclude_globs=["src/util.*"]
+        )
+        assert sorted(f.path for f in report.files) == ["README.md", "src/main.py"]
+        assert report.total_tokens == 120
+
+    def test_outside_project_blocked(self, tools: FilesystemTools):
+        """Test that paths outside the project are rejected."""
+        with pytest.raises(SecurityError):
+            tools.estimate_tokens(["../secret.txt"])
+
+    def test_symlink_escape_not_read(self, tmp_path: Path):
+        """Test that links to files outside the project are never read."""
+        project = tmp_path / "proj"
+        project.mkdir()
+        (tmp_path / "outside").mkdir()
+        (tmp_path / "outside" / "secret.txt").write_text("hunter2 hunter2")
+        (project / "main.py").write_text("x" * 8)
+        (project / "link.txt").symlink_to(tmp_path / "outside" / "secret.txt")
+        tools = FilesystemTools(PathValidator(project))
+
+        report = tools.estimate_tokens_tree()
+        assert [f.path for f in report.files] == ["main.py"]
+        assert report.total_tokens == 2
+
+        with pytest.raises(SecurityError, match="outside allowed directories"):
+            tools.estimate_tokens(["link.txt"])
+
+
+class TestSearchCodeOptions:
+    """Tests for search_code literal, filename, glob, and limit options."""
+
+    @pytest.fixture
+    def tools(self, tmp_path: Path) -> FilesystemTools:
+        """Create a fixture tree with source, docs, ignored, and binary files."""
+        (tmp_path / ".gitignore").write_text("target/\n")
+        (tmp_path / "src").mkdir()
+        (tmp_path / "src" / "search.rs").write_text("fn search(a: i32) {}\n")
+        (tmp_path / "src" / "main.rs").write_text("    search(1 + 2);\n")
+        (tmp_path / "docs").mkdir()
+        (tmp_path / "docs" / "search.md").write_text("Call search(1 + 2).\n")
+        (tmp_path / "target").mkdir()
+        (tmp_path / "target" / "search.rs").write_text("search(1 + 2)\n")
+        (tmp_path / "blob.bin").write_bytes(b"search(1 + 2)\x00\x01")
+        return FilesystemTools(PathValidator(tmp_path))
+
+    def test_literal_mode(self, tools: FilesystemTools):
+        """Test that literal mode does not interpret regex metacharacters."""
+        literal = tools.search_code("search(1 + 2)", regex=False)
+        as_regex = tools.search_code("search(1 + 2)")
+
+        assert {m.path for m in literal.matches} == {"src/main.rs", "docs/search.md"}
+        assert as_regex.matches == []
+
+    def test_offsets_match_stripped_line(self, tools: FilesystemTools):
+        """Test that offsets index into the reported line content."""
+        (match,) = tools.search_code("search(1", regex=False, path="src").matches
+
+        assert match.line_content[match.match_start : match.match_end] == "search(1"
+
+    def test_offsets_clamped_to_line_content(self, tmp_path: Path):
+        """Test that matches in stripped whitespace stay within line_content."""
+        (tmp_path / "pad.txt").write_text("  héllo wörld   \n")
+        tools = FilesystemTools(PathValidator(tmp_path))
+
+        (tail,) = tools.search_code(r"d\s+$").matches
+        (word,) = tools.search_code("wörld").matches
+
+        assert tail.match_end == len(tail.line_content)
+        assert tail.line_content[tail.match_start : tail.match_end] == "d"
+        assert word.line_content[word.match_start : word.match_end] == "wörld"
+
+    def test_skips_binary_files(self, tools: FilesystemTools):
+        """Test that files with NUL bytes are not searched."""
+        result = tools.search_code("search", regex=False)
+
+        assert "blob.bin" not in {m.path for m in result.matches}
+
+    def test_filenames_only(self, tools: FilesystemTools):
+        """Test matching relative paths instead of file contents."""
+        result = tools.search_code(r"search\.\w+$", filenames_only=True)
+
+        assert {m.path for m in result.matches} == {"src/search.rs", "docs/search.md"}
+        assert all(m.line_number == 0 for m in result.matches)
+        match = result.matches[0]
+        assert match.line_content[match.match_start : match.match_end].startswith(
+            "search."
+        )
+
+    def test_include_and_exclude_globs(self, tools: FilesystemTools):
+        """Test name and path globs narrowing the searched files."""
+        included = tools.search_code("search", include_globs=["*.rs"])
+        excluded = tools.search_code(
+            "search", include_globs=["*.rs", "*.md"], exclude_globs=["src/*"]
+        )
+
+        assert {m.path for m in included.matches} == {"src/main.rs", "src/search.rs"}
+        assert {m.path for m in excluded.matches} == {"docs/search.md"}
+
+    def test_max_results_cutoff(self, tools: FilesystemTools):
+        """Test that results stop at max_results and are marked truncated."""
+        result = tools.search_code("search", max_results=2)
+
+        assert result.total_matches == 2
+        assert result.truncated is True
+
+    def test_parallel_scan_keeps_walk_order(self, tmp_path: Path):
+        """Test limits and ordering across several worker batches."""
+        for i in range(150):
+            (tmp_path / f"f{i:03}.txt").write_text(f"hit {i:03}\nhit again\n")
+        tools = FilesystemTools(PathValidator(tmp_path))
+        walk_order = [rel for _, rel in tools._walk_files(tmp_path)]
+
+        full = tools.search_code("hit", max_results=1000)
+        limited = tools.search_code("hit", max_results=101)
+        capped = tools.search_code("hit", max_scan_bytes=40 * 18)
+
+        assert [m.path for m in full.matches[::2]] == walk_order
+        assert limited.matches == full.matches[:101]
+        assert limited.truncated is True
+        assert capped.files_searched == 40
+        assert capped.truncated is True
+
+    def test_scan_size_cap(self, tools: FilesystemTools):
+        """Test that the scanned-bytes cap stops the search early."""
+        result = tools.search_code("search", max_scan_bytes=1)
+
+        assert result.files_searched == 1
+        assert result.truncated is True
@@ -0,0 +1,64 @@
+"""Tests for fuzzy path matching."""
+
+from test_ai.tools.fuzzy import fuzzy_match
+
+
+class TestFuzzyMatch:
+    """Tests for fuzzy_match."""
+
+    def test_subsequence_required(self):
+        """Test that characters must appear in order."""
+        assert fuzzy_match("abc", "a/b/c.py") is not None
+        assert fuzzy_match("cba", "a/b/c.py") is None
+
+    def test_empty_query_matches_everything(self):
+        """Test that an empty query matches with no positions."""
+        assert fuzzy_match("", "anything") == (0, [])
+
+    def test_positions_point_at_matched_characters(self):
+        """Test that positions can be used for highlighting."""
+        candidate = "src/tools/filesystem.py"
+
+        _, positions = fuzzy_match("fsys", candidate)
+
+        assert "".join(candidate[p] for p in positions).lower() == "fsys"
+
+    def test_prefers_file_name_match(self):
+        """Test that a tighter match inside the file name is preferred."""
+        _, positions = fuzzy_match("main", "xmxaxixn/main.py")
+
+        assert positions == [9, 10, 11, 12]
+
+    def test_whole_word_beats_spread(self):
+        """Test that a whole-word match outscores one spread across words."""
+        whole, _ = fuzzy_match("main", "src/main.py")
+        spread, _ = fuzzy_match("main", "src/my_admin_tool.py")
+
+        assert whole > spread
+
+    def test_boundaries_beat_mid_word(self):
+        """Test that word-boundary matches outscore mid-word matches."""
+        boundary, _ = fuzzy_match("fb", "foo_bar.py")
+        mid_word, _ = fuzzy_match("fb", "xfxbx.py")
+
+        assert boundary > mid_word
+
+    def test_consecutive_beats_scattered(self):
+        """Test that contiguous matches outscore scattered ones."""
+        contiguous, _ = fuzzy_match("test", "test_api.py")
+        scattered, _ = fuzzy_match("test", "the_easy_state.py")
+
+        assert contiguous > scattered
+
+    def test_smart_case(self):
+        """Test case-insensitive matching unless the query has uppercase."""
+        assert fuzzy_match("readme", "README.md") is not None
+        assert fuzzy_match("README", "readme.md") is None
+        assert fuzzy_match("Read", "README.md") is None
+
+    def test_camel_case_boundary(self):
+        """Test that camelCase humps count as boundaries."""
+        camel, _ = fuzzy_match("ft", "fileTools.ts")
+        flat, _ = fuzzy_match("ft", "filetools.ts")
+
+        assert camel > flat
@@ -0,0 +1,159 @@
+"""Tests for the ignore-file matcher used by filesystem tools."""
+
+import shutil
+import subprocess
+from pathlib import Path
+
+import pytest
+
+from test_ai.tools.ignore import IgnoreMatcher, parse_ignore_lines
+
+ROOT_GITIGNORE = """\
+# build output
+*.log
+!keep.log
+build/
+!build/keep.txt
+/root_only.txt
+docs/**/*.tmp
+**/cache
+tmp*/
+\\#hash.txt
+"""
+
+FIXTURE_FILES = [
+    "a.log",
+    "keep.log",
+    "root_only.txt",
+    "sub/root_only.txt",
+    "build/out.o",
+    "build/keep.txt",
+    "src/build/x.o",
+    "docs/a/b/c.tmp",
+    "docs/c.tmp",
+    "cache/x",
+    "src/cache/y",
+    "tmpdir/f",
+    "tmpfile",
+    "#hash.txt",
+    "src/generated/g.py",
+    "src/important.log",
+    "src/x.bak",
+    "src/deep/y.bak",
+    "src/main.py",
+]
+
+
+@pytest.fixture
+def fixture_tree(tmp_path: Path) -> Path:
+    """Create a tree exercising nested, negated, and anchored patterns."""
+    (tmp_path / ".gitignore").write_text(ROOT_GITIGNORE)
+    for rel in FIXTURE_FILES:
+        path = tmp_path / rel
+        path.parent.mkdir(parents=True, exist_ok=True)
+        path.write_text("x")
+    (tmp_path / "src" / ".gitignore").write_text("generated/\n!important.log\n*.bak\n")
+    (tmp_path / "src" / "deep" / ".gitignore").write_text("!*.bak\n")
+    return tmp_path
+
+
+class TestParseIgnoreLines:
+    """Tests for pattern parsing."""
+
+    def test_skips_comments_and_blanks(self):
+        """Test that comments and blank lines produce no rules."""
+        rules = parse_ignore_lines(["# comment", "", "   ", "*.pyc"])
+
+        assert [r.pattern for r in rules] == ["*.pyc"]
+
+    def test_flags(self):
+        """Test negation, directory-only, and anchoring flags."""
+        negated, dir_only, anchored = parse_ignore_lines(["!keep", "out/", "/top"])
+
+        assert negated.negated is True
+        assert dir_only.dir_only is True
+        assert dir_only.anchored is False
+        assert anchored.anchored is True
+
+    def test_escaped_hash_is_literal(self):
+        """Test that an escaped leading hash matches a literal name."""
+        (rule,) = parse_ignore_lines(["\\#notes"])
+
+        assert rule.matches("#notes", is_dir=False)
+
+
+class TestIgnoreMatcher:
+    """Tests for IgnoreMatcher."""
+
+    def test_no_ignore_files(self, tmp_path: Path):
+        """Test that nothing is ignored without ignore files."""
+        matcher = IgnoreMatcher(tmp_path)
+
+        assert matcher.is_ignored("anything.log") is False
+
+    def test_unanchored_matches_any_depth(self, fixture_tree: Path):
+        """Test that slash-free patterns match at every depth."""
+        matcher = IgnoreMatcher(fixture_tree)
+
+        assert matcher.is_ignored("a.log")
+        assert matcher.is_ignored("deep/nested/b.log")
+
+    def test_anchored_matches_root_only(self, fixture_tree: Path):
+        """Test that leading-slash patterns only match at the root."""
+        matcher = IgnoreMatcher(fixture_tree)
+
+        assert matcher.is_ignored("root_only.txt")
+        assert not matcher.is_ignored("sub/root_only.txt")
+
+    def test_dir_only_pattern(self, fixture_tree: Path):
+        """Test that trailing-slash patterns ignore directories, not files."""
+        matcher = IgnoreMatcher(fixture_tree)
+
+        assert matcher.is_ignored("tmpdir", is_dir=True)
+        assert not matcher.is_ignored("tmpfile", is_dir=False)
+
+    def test_cannot_reinclude_inside_ignored_dir(self, fixture_tree: Path):
+        """Test that negation cannot rescue files in an ignored directory."""
+        matcher = IgnoreMatcher(fixture_tree)
+
+        assert matcher.is_ignored("build/keep.txt")
+
+    def test_nested_gitignore_overrides_parent(self, fixture_tree: Path):
+        """Test that deeper ignore files take precedence."""
+        matcher = IgnoreMatcher(fixture_tree)
+
+        assert not matcher.is_ignored("src/important.log")
+        assert matcher.is_ignored("src/x.bak")
+        assert not matcher.is_ignored("src/deep/y.bak")
+
+    def test_gorgonignore_applied_last(self, fixture_tree: Path):
+        """Test that .gorgonignore rules are applied after .gitignore."""
+        (fixture_tree / ".gorgonignore").write_text("src/main.py\n!keep.log\n!a.log\n")
+        matcher = IgnoreMatcher(fixture_tree)
+
+        assert matcher.is_ignored("src/main.py")
+        assert not matcher.is_ignored("a.log")
+
+    def test_windows_separators(self, fixture_tree: Path):
+        """Test that backslash-separated paths are normalized."""
+        matcher = IgnoreMatcher(fixture_tree)
+
+        assert matcher.is_ignored("src\\generated\\g.py")
+
+    @pytest.mark.skipif(shutil.which("git") is None, reason="git not installed")
+    def test_matches_git_check_ignore(self, fixture_tree: Path):
+        """Test conformance with git's own check-ignore on the fixture tree."""
+        subprocess.run(["git", "init", "-q"], cwd=fixture_tree, check=True)
+        result = subprocess.run(
+            ["git", "check-ignore", "--stdin"],
+            cwd=fixture_tree,
+            input="\n".join(FIXTURE_FILES),
+            capture_output=True,
+            text=True,
+        )
+        git_ignored = set(result.stdout.split("\n")) - {""}
+
+        matcher = IgnoreMatcher(fixture_tree)
+        ours = {rel for rel in FIXTURE_FILES if matcher.is_ignored(rel)}
+
+        assert ours == git_ignored
@@ -0,0 +1,105 @@
+"""Tests for language detection."""
+
+from pathlib import Path
+
+import pytest
+
+from test_ai.tools.filesystem import FilesystemTools
+from test_ai.tools.language import (
+    EXTENSION_LANGUAGES,
+    FILENAME_LANGUAGES,
+    INTERPRETER_LANGUAGES,
+    LANGUAGE_IDS,
+    PLAINTEXT,
+    detect_language,
+)
+from test_ai.tools.safety import PathValidator
+
+# (path, leading content, expected language)
+FIXTURES = [
+    ("src/main.rs", None, "rust"),
+    ("app/component.tsx", None, "typescriptreact"),
+    ("Makefile", None, "makefile"),
+    ("GNUmakefile", None, "makefile"),
+    ("rules.mk", None, "makefile"),
+    ("Dockerfile", None, "dockerfile"),
+    ("Dockerfile.dev", None, "dockerfile"),
+    ("CMakeLists.txt", None, "cmake"),
+    ("Gemfile", None, "ruby"),
+    ("include/util.h", "int add(int a, int b);\n", "c"),
+    ("include/vec.h", "namespace geo {\nclass Vec {};\n}\n", "cpp"),
+    ("include/view.h", "@interface View : NSObject\n@end\n", "objective-c"),
+    ("bin/deploy", "#!/bin/bash\nset -e\n", "shell"),
+    ("bin/tool", "#!/usr/bin/env python3.12\nprint()\n", "python"),
+    ("bin/serve", "#!/usr/bin/env -S node --no-warnings\n", "javascript"),
+    ("bin/run", "#!/usr/bin/env FOO=1 ruby\n", "ruby"),
+    ("index", "<?php echo 1;\n", "php"),
+    ("feed", "\ufeff<?xml version='1.0'?>\n<rss/>\n", "xml"),
+    ("page", "<!DOCTYPE html>\n<html></html>\n", "html"),
+    ("README", "Just some words.\n", PLAINTEXT),
+    ("data.unknownext", None, PLAINTEXT),
+]
+
+
+class TestDetectLanguage:
+    """Tests for detect_language."""
+
+    @pytest.mark.parametrize("path,sample,expected", FIXTURES)
+    def test_fixtures(self, path: str, sample: str | None, expected: str):
+        """Test detection over the fixture set."""
+        language, _ = detect_language(path, sample)
+
+        assert language == expected
+
+    def test_mapping_values_are_documented(self):
+        """Test that every mapping table only produces documented ids."""
+        produced = (
+            set(FILENAME_LANGUAGES.values())
+            | set(EXTENSION_LANGUAGES.values())
+            | set(INTERPRETER_LANGUAGES.values())
+        )
+
+        assert produced <= LANGUAGE_IDS
+
+    def test_extension_beats_shebang(self):
+        """Test that an explicit extension wins over a shebang."""
+        language, _ = detect_language("script.py", "#!/bin/sh\n")
+
+        assert language == "python"
+
+    def test_unknown_has_zero_confidence(self):
+        """Test that unknown files are plaintext with zero confidence."""
+        assert detect_language("mystery", "\x00\x01binary") == (PLAINTEXT, 0.0)
+
+    def test_ambiguous_header_has_low_confidence(self):
+        """Test that a header without content is a low-confidence C guess."""
+        language, confidence = detect_language("x.h")
+
+        assert language == "c"
+        assert confidence < detect_language("x.c")[1]
+
+
+class TestFilesystemToolsLanguage:
+    """Tests for language detection through FilesystemTools."""
+
+    @pytest.fixture
+    def tools(self, tmp_path: Path) -> FilesystemTools:
+        """Create tools over a project with an extensionless script."""
+        (tmp_path / "run").write_text("#!/usr/bin/env bash\necho hi\n")
+        return FilesystemTools(PathValidator(tmp_path))
+
+    def test_reads_sample_from_file(self, tools: FilesystemTools):
+        """Test that the shebang is read from disk when no sample is given."""
+        info = tools.detect_language("run")
+
+        assert info.language == "shell"
+        assert info.confidence > 0
+
+    def test_missing_file_is_plaintext(self, tools: FilesystemTools):
+        """Test that a missing extensionless file is not an error."""
+        assert tools.detect_language("nothing").language == PLAINTEXT
+
+    def test_read_file_language_is_opt_in(self, tools: FilesystemTools):
+        """Test that read_file only detects the language when asked."""
+        assert tools.read_file("run").language is None
+        assert tools.read_file("run", include_language=True).language == "shell"
@@ -0,0 +1,268 @@
+"""Tests for the .gorgonpolicy access policy."""
+
+import os
+from pathlib import Path
+from unittest.mock import patch
+
+import pytest
+
+from test_ai.tools.filesystem import FilesystemTools
+from test_ai.tools.models import FileOperation
+from test_ai.tools.policy import AccessPolicy, parse_policy_lines
+from test_ai.tools.safety import PathValidator, PolicyViolation
+
+POLICY = """\
+# secrets are off limits
+infra/secrets/
+!infra/secrets/README.md
+ro:migrations/**
+ro: *.lock
+"""
+
+
+@pytest.fixture
+def project(tmp_path: Path) -> Path:
+    """Create a project with a policy and files it covers."""
+    (tmp_path / ".gorgonpolicy").write_text(POLICY)
+    for rel in [
+        "infra/secrets/prod.yaml",
+        "infra/secrets/README.md",
+        "infra/deploy.yaml",
+        "migrations/001_init.sql",
+        "poetry.lock",
+        "src/app.py",
+    ]:
+        path = tmp_path / rel
+        path.parent.mkdir(parents=True, exist_ok=True)
+        path.write_text("x")
+    return tmp_path
+
+
+class TestParsePolicyLines:
+    """Tests for policy parsing."""
+
+    def test_read_only_prefix(self):
+        """Test that ro: marks a rule read-only and keeps its line number."""
+        deny, read_only = parse_policy_lines(["# c", "secrets/", "ro: *.lock"])
+
+        assert deny.read_only is False
+        assert deny.line == 2
+        assert read_only.read_only is True
+        assert read_only.pattern == "ro:*.lock"
+
+
+class TestAccessPolicy:
+    """Tests for AccessPolicy.check."""
+
+    def test_deny_blocks_everything(self, project: Path):
+        """Test that a plain rule blocks reads, writes, and deletes."""
+        policy = AccessPolicy(project)
+
+        for op in FileOperation:
+            rule = policy.check("infra/secrets/prod.yaml", op)
+            assert rule is not None
+            assert rule.pattern == "infra/secrets/"
+
+    def test_cannot_lift_inside_denied_directory(self, project: Path):
+        """Test that negation cannot re-allow files in a denied directory."""
+        policy = AccessPolicy(project)
+
+        assert policy.check("infra/secrets/README.md", FileOperation.READ)
+
+    def test_lift_with_entry_pattern(self, tmp_path: Path):
+        """Test the documented way to allow one file in a denied directory."""
+        (tmp_path / ".gorgonpolicy").write_text(
+            "infra/secrets/*\n!infra/secrets/README.md\n"
+        )
+        policy = AccessPolicy(tmp_path)
+
+        assert policy.check("infra/secrets/README.md", FileOperation.READ) is None
+        assert policy.check("infra/secrets/prod.yaml", FileOperation.READ)
+
+    def test_delete_parent_of_protected_entries(self, tmp_path: Path):
+        """Test that directories holding denied or read-only entries are kept."""
+        (tmp_path / ".gorgonpolicy").write_text("infra/secrets/*\nro:migrations/**\n")
+        for rel in ["infra/secrets/prod.yaml", "migrations/001.sql", "src/a.py"]:
+            (tmp_path / rel).parent.mkdir(parents=True, exist_ok=True)
+            (tmp_path / rel).write_text("x")
+        policy = AccessPolicy(tmp_path)
+
+        for rel_dir, pattern in [
+            ("infra/secrets", "infra/secrets/*"),
+            ("infra", "infra/secrets/*"),
+            ("migrations", "ro:migrations/**"),
+        ]:
+            rule = policy.check(rel_dir, FileOperation.DELETE, is_dir=True)
+            assert rule is not None
+            assert rule.pattern == pattern
+        assert policy.check("migrations", FileOperation.WRITE, is_dir=True)
+        assert policy.check(".", FileOperation.DELETE, is_dir=True)
+
+        assert policy.check("infra", FileOperation.READ, is_dir=True) is None
+        assert policy.check("src", FileOperation.DELETE, is_dir=True) is None
+
+    def test_read_only_allows_reads(self, project: Path):
+        """Test that ro: rules only block writes and deletes."""
+        policy = AccessPolicy(project)
+
+        assert policy.check("migrations/001_init.sql", FileOperation.READ) is None
+        assert policy.check("migrations/001_init.sql", FileOperation.WRITE)
+        assert policy.check("poetry.lock", FileOperation.DELETE)
+
+    def test_unmatched_paths_allowed(self, project: Path):
+        """Test that paths without a matching rule are allowed."""
+        policy = AccessPolicy(project)
+
+        assert policy.check("infra/deploy.yaml", FileOperation.DELETE) is None
+
+    def test_policy_file_is_read_only(self, tmp_path: Path):
+        """Test that the policy file protects itself even when absent."""
+        policy = AccessPolicy(tmp_path)
+
+        assert policy.check(".gorgonpolicy", FileOperation.READ) is None
+        assert policy.check(".gorgonpolicy", FileOperation.WRITE)
+
+    def test_reloads_on_change(self, project: Path):
+        """Test that edits to the policy file apply after a refresh."""
+        policy = AccessPolicy(project)
+        assert policy.check("src/app.py", FileOperation.READ) is None
+
+        policy_file = project / ".gorgonpolicy"
+        policy_file.write_text("src/\n")
+        stat = policy_file.stat()
+        os.utime(policy_file, ns=(stat.st_atime_ns, stat.st_mtime_ns + 1_000_000))
+
+        assert policy.check("src/app.py", FileOperation.READ) is None
+        policy.refresh()
+        assert policy.check("src/app.py", FileOperation.READ) is not None
+
+
+class TestPolicyEnforcement:
+    """Tests for policy enforcement in PathValidator and FilesystemTools."""
+
+    def test_validate_path_raises_violation(self, project: Path):
+        """Test that violations carry the operation, rule, and error code."""
+        validator = PathValidator(project)
+
+        with pytest.raises(PolicyViolation, match="infra/secrets/") as exc_info:
+            validator.validate_file_for_read("infra/secrets/prod.yaml")
+
+        assert exc_info.value.code == "POLICY_DENIED"
+        assert exc_info.value.operation == FileOperation.READ
+        assert exc_info.value.rule.line == 2
+
+    def test_write_validation_uses_write_operation(self, project: Path):
+        """Test that read-only paths validate for read but not for write."""
+        validator = PathValidator(project)
+
+        validator.validate_file_for_read("migrations/001_init.sql")
+        with pytest.raises(PolicyViolation, match="ro:migrations"):
+            validator.validate_file_for_write("migrations/001_init.sql")
+
+    def test_enforcement_can_be_disabled(self, project: Path):
+        """Test that use_policy=False skips the policy."""
+        validator = PathValidator(project, use_policy=False)
+
+        validator.validate_file_for_read("infra/secrets/prod.yaml")
+
+    def test_policy_check(self, project: Path):
+        """Test the non-raising check reports the denying rule."""
+        tools = FilesystemTools(PathValidator(project))
+
+        denied = tools.policy_check("poetry.lock", FileOperation.WRITE)
+        allowed = tools.policy_check("poetry.lock")
+
+        assert denied.allowed is False
+        assert denied.rule == "ro:*.lock"
+        assert denied.line == 5
+        assert allowed.allowed is True
+        assert allowed.rule is None
+
+    def test_policy_check_reports_validation_failures(self, project: Path):
+        """Test that out-of-bounds and excluded paths are not allowed."""
+        (project / ".env").write_text("SECRET=1")
+        tools = FilesystemTools(PathValidator(project))
+
+        for path in ["/etc/passwd", "../etc/passwd", ".env"]:
+            check = tools.policy_check(path)
+            assert check.allowed is False, path
+            assert check.reason
+            assert check.rule is None
+
+        denied = tools.policy_check("infra/secrets/prod.yaml")
+        assert "infra/secrets/" in denied.reason
+
+    def test_policy_check_directory_delete(self, project: Path):
+        """Test that checking a directory delete covers its contents."""
+        tools = FilesystemTools(PathValidator(project))
+
+        check = tools.policy_check("migrations", FileOperation.DELETE)
+
+        assert check.allowed is False
+        assert check.rule == "ro:migrations/**"
+        assert tools.policy_check("src", FileOperation.DELETE).allowed is True
+
+    def test_denied_paths_hidden_from_listings(self, project: Path):
+        """Test that recursive tools skip paths the agent may not read."""
+        tools = FilesystemTools(PathValidator(project))
+
+        listed = {e.path for e in tools.list_files("infra", recursive=True).entries}
+
+        assert listed == {"infra/deploy.yaml"}
+
+    def test_path_kind_respects_policy(self, project: Path):
+        """Test that path_kind does not reveal denied entries."""
+        tools = FilesystemTools(PathValidator(project))
+
+        with pytest.raises(PolicyViolation) as exc_info:
+            tools.path_kind("infra/secrets")
+        assert exc_info.value.path == "infra/secrets"
+
+        with pytest.raises(PolicyViolation) as exc_info:
+            tools.path_kind("infra/secrets/prod.yaml")
+        assert exc_info.value.path == "infra/secrets/prod.yaml"
+        assert str(project) not in str(exc_info.value)
+
+        assert tools.path_kind("migrations/001_init.sql").exists is True
+
+    def test_validation_picks_up_policy_edits(self, project: Path):
+        """Test that single-path validation sees an edited policy."""
+        validator = PathValidator(project)
+        validator.validate_file_for_read("src/app.py")
+
+        policy_file = project / ".gorgonpolicy"
+        policy_file.write_text("src/\n")
+        stat = policy_file.stat()
+        os.utime(policy_file, ns=(stat.st_atime_ns, stat.st_mtime_ns + 1_000_000))
+
+        with pytest.raises(PolicyViolation):
+            validator.validate_file_for_read("src/app.py")
+
+    def test_search_does_not_refresh_per_file(self, project: Path):
+        """Test that search_code reloads the policy a constant number of times."""
+        for i in range(20):
+            (project / "src" / f"m{i}.py").write_text("needle")
+        tools = FilesystemTools(PathValidator(project))
+
+        with patch.object(
+            AccessPolicy, "refresh", autospec=True, side_effect=AccessPolicy.refresh
+        ) as refresh:
+            result = tools.search_code("needle")
+
+        assert result.total_matches == 20
+        assert refresh.call_count <= 2
+
+    def test_walk_refreshes_policy_once(self, project: Path):
+        """Test that a recursive walk does not re-read the policy per entry."""
+        for i in range(20):
+            (project / "src" / f"m{i}.py").write_text("x")
+        tools = FilesystemTools(PathValidator(project))
+
+        with patch.object(
+            AccessPolicy, "refresh", autospec=True, side_effect=AccessPolicy.refresh
+        ) as refresh:
+            files = tools.recent_files().files
+
+        # Once for validating the directory, once when the walk starts
+        assert len(files) > 20
+        assert refresh.call_count <= 2
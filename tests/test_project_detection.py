@@ -0,0 +1,167 @@
+"""Tests for project type detection."""
+
+import json
+from pathlib import Path
+
+import pytest
+
+from test_ai.tools.filesystem import FilesystemTools
+from test_ai.tools.models import Ecosystem
+from test_ai.tools.project import detect_project
+from test_ai.tools.safety import PathValidator, SecurityError
+
+
+class TestDetectProject:
+    """Tests for detect_project."""
+
+    def test_plain_folder(self, tmp_path: Path):
+        """Test that a folder without manifests reports no ecosystems."""
+        (tmp_path / "notes.txt").write_text("hello")
+
+        info = detect_project(tmp_path)
+
+        assert info.ecosystems == []
+        assert info.warnings == []
+
+    def test_cargo_workspace(self, tmp_path: Path):
+        """Test Cargo package, binary targets, and workspace members."""
+        (tmp_path / "Cargo.toml").write_text(
+            "[package]\n"
+            'name = "app"\n'
+            'version = "0.3.1"\n'
+            "\n"
+            "[[bin]]\n"
+            'name = "app-cli"\n'
+            "\n"
+            "[workspace]\n"
+            'members = ["crates/*", "tools/gen"]\n'
+        )
+
+        (cargo,) = detect_project(tmp_path).ecosystems
+
+        assert cargo.ecosystem == Ecosystem.CARGO
+        assert cargo.name == "app"
+        assert cargo.version == "0.3.1"
+        assert cargo.targets == ["app-cli"]
+        assert cargo.members == ["crates/*", "tools/gen"]
+
+    def test_cargo_inherited_version(self, tmp_path: Path):
+        """Test that a workspace-inherited version is reported as unknown."""
+        (tmp_path / "Cargo.toml").write_text(
+            '[package]\nname = "member"\nversion.workspace = true\n'
+        )
+
+        (cargo,) = detect_project(tmp_path).ecosystems
+
+        assert cargo.name == "member"
+        assert cargo.version is None
+
+    def test_npm_scripts_and_workspaces(self, tmp_path: Path):
+        """Test package.json scripts and object-form workspaces."""
+        (tmp_path / "package.json").write_text(
+            json.dumps(
+                {
+                    "name": "web",
+                    "version": "1.2.0",
+                    "scripts": {"build": "vite build", "test": "vitest"},
+                    "workspaces": {"packages": ["packages/*"]},
+                }
+            )
+        )
+
+        (npm,) = detect_project(tmp_path).ecosystems
+
+        assert npm.ecosystem == Ecosystem.NPM
+        assert npm.scripts == {"build": "vite build", "test": "vitest"}
+        assert npm.members == ["packages/*"]
+
+    def test_pyproject_and_poetry(self, tmp_path: Path):
+        """Test PEP 621 metadata with a Poetry fallback."""
+        (tmp_path / "pyproject.toml").write_text(
+            "[tool.poetry]\n"
+            'name = "svc"\n'
+            'version = "2.0.0"\n'
+            "[tool.poetry.scripts]\n"
+            'svc = "svc.cli:main"\n'
+        )
+
+        (python,) = detect_project(tmp_path).ecosystems
+
+        assert python.name == "svc"
+        assert python.version == "2.0.0"
+        assert python.scripts == {"svc": "svc.cli:main"}
+
+    def test_go_mod(self, tmp_path: Path):
+        """Test module path extraction from go.mod."""
+        (tmp_path / "go.mod").write_text("module example.com/tool\n\ngo 1.22\n")
+
+        (go,) = detect_project(tmp_path).ecosystems
+
+        assert go.name == "example.com/tool"
+
+    def test_mixed_repository(self, tmp_path: Path):
+        """Test that every ecosystem in a mixed repo is reported."""
+        (tmp_path / "Cargo.toml").write_text('[package]\nname = "core"\n')
+        (tmp_path / "package.json").write_text('{"name": "ui"}')
+        (tmp_path / "Gemfile").write_text("source 'https://rubygems.org'\n")
+
+        info = detect_project(tmp_path)
+
+        assert [e.ecosystem for e in info.ecosystems] == [
+            Ecosystem.CARGO,
+            Ecosystem.NPM,
+            Ecosystem.RUBY,
+        ]
+
+    @pytest.mark.parametrize(
+        "filename,content",
+        [
+            ("Cargo.toml", "[package\nname = "),
+            ("package.json", "{not json"),
+            ("package.json", "[1, 2]"),
+            ("pyproject.toml", "= broken"),
+            ("package.json", '{"name": 5}'),
+            ("package.json", '{"workspaces": "packages/*"}'),
+            ("package.json", '{"scripts": {"build": ["tsc"]}}'),
+            ("Cargo.toml", 'package = "app"'),
+            ("Cargo.toml", '[workspace]\nmembers = "crates/*"\n'),
+            ("Cargo.toml", "[[bin]]\nname = 3\n"),
+        ],
+    )
+    def test_malformed_manifest_is_warning(
+        self, tmp_path: Path, filename: str, content: str
+    ):
+        """Test that malformed manifests are detected and reported as warnings."""
+        (tmp_path / filename).write_text(content)
+
+        info = detect_project(tmp_path)
+
+        assert len(info.ecosystems) == 1
+        assert info.ecosystems[0].name is None
+        assert info.ecosystems[0].members == []
+        assert len(info.warnings) == 1
+        assert info.warnings[0].startswith(f"{filename}:")
+
+
+class TestFilesystemToolsDetectProject:
+    """Tests for FilesystemTools.detect_project."""
+
+    def test_subdirectory_manifest_paths(self, tmp_path: Path):
+        """Test that manifest paths are relative to the project root."""
+        sub = tmp_path / "frontend"
+        sub.mkdir()
+        (sub / "package.json").write_text('{"name": "ui"}')
+        tools = FilesystemTools(PathValidator(tmp_path))
+
+        info = tools.detect_project("frontend")
+
+        assert info.path == "frontend"
+        assert info.ecosystems[0].manifest == "frontend/package.json"
+
+    def test_rejects_outside_root(self, tmp_path: Path):
+        """Test that paths outside the project are rejected."""
+        (tmp_path / "inner").mkdir()
+        tools = FilesystemTools(PathValidator(tmp_path / "inner"))
+
+        with pytest.raises(SecurityError):
+            tools.detect_project("..")